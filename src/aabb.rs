@@ -0,0 +1,197 @@
+use crate::{vec3, Vec3};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 3D axis-aligned bounding box.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+/// Easy constructor.
+pub const fn aabb(min: Vec3, max: Vec3) -> Aabb {
+    Aabb { min, max }
+}
+
+impl Aabb {
+    /// An empty (zeroed) box.
+    pub const EMPTY: Self = Self {
+        min: Vec3::ZERO,
+        max: Vec3::ZERO,
+    };
+
+    /// Create a new box.
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        aabb(min, max)
+    }
+
+    /// Create a box centered on the position with the provided size.
+    pub fn from_center_size(center: Vec3, size: Vec3) -> Self {
+        aabb(center - size * 0.5, center + size * 0.5)
+    }
+
+    /// Create the smallest box containing all the points, or an empty box if there are none.
+    pub fn from_points(points: &[Vec3]) -> Self {
+        match points.split_first() {
+            Some((first, rest)) => rest.iter().fold(aabb(*first, *first), |b, p| {
+                aabb(b.min.min(*p), b.max.max(*p))
+            }),
+            None => Self::EMPTY,
+        }
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// The eight corners of the box, with the min corner first and the max corner last.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            vec3(a.x, a.y, a.z),
+            vec3(b.x, a.y, a.z),
+            vec3(a.x, b.y, a.z),
+            vec3(b.x, b.y, a.z),
+            vec3(a.x, a.y, b.z),
+            vec3(b.x, a.y, b.z),
+            vec3(a.x, b.y, b.z),
+            vec3(b.x, b.y, b.z),
+        ]
+    }
+
+    pub fn volume(&self) -> f32 {
+        let s = self.size();
+        s.x * s.y * s.z
+    }
+
+    pub fn contains(&self, p: Vec3) -> bool {
+        p.x >= self.min.x
+            && p.y >= self.min.y
+            && p.z >= self.min.z
+            && p.x < self.max.x
+            && p.y < self.max.y
+            && p.z < self.max.z
+    }
+
+    pub fn contains_aabb(&self, b: &Self) -> bool {
+        b.min.x >= self.min.x
+            && b.min.y >= self.min.y
+            && b.min.z >= self.min.z
+            && b.max.x <= self.max.x
+            && b.max.y <= self.max.y
+            && b.max.z <= self.max.z
+    }
+
+    pub fn overlaps(&self, b: &Self) -> bool {
+        self.min.x < b.max.x
+            && self.min.y < b.max.y
+            && self.min.z < b.max.z
+            && self.max.x > b.min.x
+            && self.max.y > b.min.y
+            && self.max.z > b.min.z
+    }
+
+    /// The box where the two boxes overlap, if they do.
+    pub fn intersection(&self, b: &Self) -> Option<Self> {
+        let min = self.min.max(b.min);
+        let max = self.max.min(b.max);
+        if max.x > min.x && max.y > min.y && max.z > min.z {
+            Some(aabb(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, b: &Self) -> Self {
+        aabb(self.min.min(b.min), self.max.max(b.max))
+    }
+
+    pub fn translate(&self, amount: Vec3) -> Self {
+        aabb(self.min + amount, self.max + amount)
+    }
+
+    /// Cast a ray against the box, returning how far along `dir` it first touches it.
+    /// Rays that start inside the box hit it immediately, at `0.0`.
+    pub fn ray_intersect(&self, origin: Vec3, dir: Vec3) -> Option<f32> {
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+        for i in 0..3 {
+            if dir[i] == 0.0 {
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+            } else {
+                let a = (self.min[i] - origin[i]) / dir[i];
+                let b = (self.max[i] - origin[i]) / dir[i];
+                near = near.max(a.min(b));
+                far = far.min(a.max(b));
+                if near > far {
+                    return None;
+                }
+            }
+        }
+        Some(near)
+    }
+}
+
+impl AsRef<[f32]> for Aabb {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 6) }
+    }
+}
+
+impl fmt::Display for Aabb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn containment_overlap_and_ray() {
+        let a = Aabb::from_center_size(Vec3::ZERO, vec3(2.0, 2.0, 2.0));
+        assert_eq!(a, aabb(vec3(-1.0, -1.0, -1.0), Vec3::ONE));
+        assert!(a.contains(Vec3::ZERO));
+        assert!(!a.contains(vec3(0.0, 1.5, 0.0)));
+
+        let b = a.translate(vec3(1.5, 0.0, 0.0));
+        assert!(a.overlaps(&b));
+        let overlap = a.intersection(&b).unwrap();
+        assert!(overlap.size().approx_eq(&vec3(0.5, 2.0, 2.0)));
+        assert!(a.union(&b).contains_aabb(&b));
+        assert!(!a.overlaps(&a.translate(vec3(0.0, 0.0, 2.0))));
+        assert_eq!(a.intersection(&a.translate(vec3(0.0, 0.0, 2.0))), None);
+
+        let points = [
+            vec3(1.0, -2.0, 0.0),
+            vec3(-1.0, 3.0, 2.0),
+            vec3(0.0, 0.0, -1.0),
+        ];
+        let bounds = Aabb::from_points(&points);
+        assert_eq!(bounds, aabb(vec3(-1.0, -2.0, -1.0), vec3(1.0, 3.0, 2.0)));
+        assert!(bounds
+            .corners()
+            .iter()
+            .all(|c| bounds.union(&aabb(*c, *c)) == bounds));
+
+        let t = a.ray_intersect(vec3(-5.0, 0.5, 0.0), Vec3::RIGHT).unwrap();
+        assert!(crate::approx_f32(t, 4.0));
+        assert_eq!(a.ray_intersect(vec3(-5.0, 0.5, 0.0), Vec3::LEFT), None);
+        assert_eq!(a.ray_intersect(Vec3::ZERO, Vec3::UP), Some(0.0));
+    }
+}
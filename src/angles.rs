@@ -0,0 +1,300 @@
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An angle in radians.
+#[derive(Default, Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+#[repr(C)]
+pub struct Radians(pub f32);
+
+/// An angle in degrees.
+#[derive(Default, Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+#[repr(C)]
+pub struct Degrees(pub f32);
+
+impl From<f32> for Radians {
+    fn from(val: f32) -> Self {
+        Radians(val)
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(val: Degrees) -> Self {
+        Radians(crate::rad(val.0))
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(val: Radians) -> Self {
+        Degrees(crate::deg(val.0))
+    }
+}
+
+impl Radians {
+    /// The angle of the vector `(x, y)`, measured from the positive x-axis.
+    pub fn atan2(y: f32, x: f32) -> Self {
+        Radians(y.atan2(x))
+    }
+
+    /// The sine of the angle.
+    pub fn sin(self) -> f32 {
+        self.0.sin()
+    }
+
+    /// The cosine of the angle.
+    pub fn cos(self) -> f32 {
+        self.0.cos()
+    }
+
+    /// The tangent of the angle.
+    pub fn tan(self) -> f32 {
+        self.0.tan()
+    }
+
+    /// The sine and cosine of the angle.
+    pub fn sin_cos(self) -> (f32, f32) {
+        (self.sin(), self.cos())
+    }
+}
+
+impl Degrees {
+    /// The sine of the angle.
+    pub fn sin(self) -> f32 {
+        Radians::from(self).sin()
+    }
+
+    /// The cosine of the angle.
+    pub fn cos(self) -> f32 {
+        Radians::from(self).cos()
+    }
+
+    /// The tangent of the angle.
+    pub fn tan(self) -> f32 {
+        Radians::from(self).tan()
+    }
+
+    /// The sine and cosine of the angle.
+    pub fn sin_cos(self) -> (f32, f32) {
+        Radians::from(self).sin_cos()
+    }
+}
+
+impl AsRef<[f32]> for Radians {
+    fn as_ref(&self) -> &[f32] {
+        core::slice::from_ref(&self.0)
+    }
+}
+
+impl fmt::Display for Radians {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" rad")
+    }
+}
+
+impl Neg for Radians {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Radians(-self.0)
+    }
+}
+
+impl<T: Into<Radians>> Add<T> for Radians {
+    type Output = Self;
+    fn add(self, other: T) -> Self {
+        Radians(self.0 + other.into().0)
+    }
+}
+
+impl<T: Into<Radians>> AddAssign<T> for Radians {
+    fn add_assign(&mut self, rhs: T) {
+        *self = self.add(rhs);
+    }
+}
+
+impl<T: Into<Radians>> Sub<T> for Radians {
+    type Output = Self;
+    fn sub(self, other: T) -> Self {
+        Radians(self.0 - other.into().0)
+    }
+}
+
+impl<T: Into<Radians>> SubAssign<T> for Radians {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Mul<f32> for Radians {
+    type Output = Self;
+    fn mul(self, n: f32) -> Self {
+        Radians(self.0 * n)
+    }
+}
+
+impl MulAssign<f32> for Radians {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Div<f32> for Radians {
+    type Output = Self;
+    fn div(self, n: f32) -> Self {
+        Radians(self.0 / n)
+    }
+}
+
+impl DivAssign<f32> for Radians {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div(rhs);
+    }
+}
+
+/// Compares the raw degree values with [Approx](trait.Approx.html), so convert both sides to
+/// the same unit first rather than comparing `Degrees` against `Radians` directly.
+impl AsRef<[f32]> for Degrees {
+    fn as_ref(&self) -> &[f32] {
+        core::slice::from_ref(&self.0)
+    }
+}
+
+impl fmt::Display for Degrees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" deg")
+    }
+}
+
+impl Neg for Degrees {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Degrees(-self.0)
+    }
+}
+
+impl<T: Into<Degrees>> Add<T> for Degrees {
+    type Output = Self;
+    fn add(self, other: T) -> Self {
+        Degrees(self.0 + other.into().0)
+    }
+}
+
+impl<T: Into<Degrees>> AddAssign<T> for Degrees {
+    fn add_assign(&mut self, rhs: T) {
+        *self = self.add(rhs);
+    }
+}
+
+impl<T: Into<Degrees>> Sub<T> for Degrees {
+    type Output = Self;
+    fn sub(self, other: T) -> Self {
+        Degrees(self.0 - other.into().0)
+    }
+}
+
+impl<T: Into<Degrees>> SubAssign<T> for Degrees {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = self.sub(rhs);
+    }
+}
+
+impl Mul<f32> for Degrees {
+    type Output = Self;
+    fn mul(self, n: f32) -> Self {
+        Degrees(self.0 * n)
+    }
+}
+
+impl MulAssign<f32> for Degrees {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Div<f32> for Degrees {
+    type Output = Self;
+    fn div(self, n: f32) -> Self {
+        Degrees(self.0 / n)
+    }
+}
+
+impl DivAssign<f32> for Degrees {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PI;
+
+    #[test]
+    fn degrees_radians_convert() {
+        let r: Radians = Degrees(180.0).into();
+        assert!(crate::approx_f32(r.0, crate::PI));
+        let d: Degrees = Radians(crate::PI / 2.0).into();
+        assert!(crate::approx_f32(d.0, 90.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() {
+        let json = serde_json::to_string(&Radians(1.5)).unwrap();
+        assert_eq!(json, "1.5");
+        let back: Radians = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.0, 1.5);
+        let deg: Degrees = serde_json::from_str("90.0").unwrap();
+        assert_eq!(deg.0, 90.0);
+    }
+
+    #[test]
+    fn arithmetic_and_display() {
+        let sum = Degrees(45.0) + Degrees(45.0);
+        assert!(crate::approx_f32(sum.0, 90.0));
+        let mixed = Radians(crate::PI / 4.0) + Degrees(45.0);
+        assert!(crate::approx_f32(mixed.0, crate::PI / 2.0));
+        let mut a = Radians(1.0);
+        a -= Radians(0.25);
+        a *= 2.0;
+        a /= 3.0;
+        assert!(crate::approx_f32((-a).0, -0.5));
+        assert_eq!(alloc::format!("{:.2}", Radians(1.256)), "1.26 rad");
+        assert_eq!(alloc::format!("{}", Degrees(90.0)), "90 deg");
+    }
+
+    #[test]
+    fn trig_helpers() {
+        assert!(crate::approx_f32(Degrees(90.0).sin(), 1.0));
+        assert!(crate::approx_f32(Radians(0.0).cos(), 1.0));
+        assert!(crate::approx_f32(Degrees(45.0).tan(), 1.0));
+        let (s, c) = Radians(0.3).sin_cos();
+        assert!(crate::approx_f32(s, 0.3f32.sin()) && crate::approx_f32(c, 0.3f32.cos()));
+        assert!(crate::approx_f32(
+            Radians::atan2(1.0, 0.0).0,
+            crate::PI / 2.0
+        ));
+    }
+
+    #[test]
+    fn angles_through_approx() {
+        let a = Radians(0.5);
+        let b = Radians(0.5 + 1e-8);
+        assert!(crate::approx(&a, &b));
+        assert!(!crate::approx(&a, &Radians(0.51)));
+        assert_eq!(a.as_ref(), &[0.5]);
+
+        assert!(crate::approx(&Degrees(30.0), &Degrees(30.0)));
+        assert!(!crate::approx(&Degrees(30.0), &Degrees(31.0)));
+        // raw values are compared, so a matching angle in another unit isn't approximate
+        assert!(!crate::approx(&Degrees(180.0), &Radians(PI)));
+        assert!(crate::approx(&Radians::from(Degrees(180.0)), &Radians(PI)));
+    }
+}
@@ -3,6 +3,11 @@ pub fn approx_f32(a: f32, b: f32) -> bool {
     approx::abs_diff_eq!(a, b)
 }
 
+/// Check if two slices are the same length and all of their values are approximately equal.
+pub fn approx_slice(a: &[f32], b: &[f32]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| approx_f32(a, b))
+}
+
 // Check if two values that can be references as &[f32] are approximate
 pub fn approx<A, B>(a: &A, b: &B) -> bool
 where
@@ -28,8 +33,22 @@ where
 {
     /// Check if the two values are approximately equal.
     fn approx(&self, other: &B) -> bool {
-        let a = self.as_ref();
-        let b = other.as_ref();
-        a.len() == b.len() && (0..a.len()).all(|i| unsafe { approx_f32(*a.get_unchecked(i), *b.get_unchecked(i)) })
+        approx_slice(self.as_ref(), other.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vec3, vec4};
+
+    #[test]
+    fn length_mismatch_is_not_approx() {
+        let a = vec3(1.0, 2.0, 3.0);
+        let b = vec4(1.0, 2.0, 3.0, 0.0);
+        assert!(!approx(&a, &b));
+        assert!(!a.approx(&b));
+        assert!(!approx_slice(&[1.0, 2.0], &[1.0]));
+        assert!(approx_slice(a.as_ref(), &b.as_ref()[..3]));
     }
 }
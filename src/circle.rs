@@ -0,0 +1,113 @@
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Rect, Vec2};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A circle, defined by its center point and radius.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+/// Easy constructor.
+pub const fn circle(center: Vec2, radius: f32) -> Circle {
+    Circle { center, radius }
+}
+
+impl Circle {
+    /// Create a new circle.
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        circle(center, radius)
+    }
+
+    pub fn area(&self) -> f32 {
+        crate::PI * self.radius * self.radius
+    }
+
+    pub fn circumference(&self) -> f32 {
+        crate::TAU * self.radius
+    }
+
+    /// The smallest rectangle containing the circle.
+    pub fn bounding_rect(&self) -> Rect {
+        Rect::centered(self.center, self.radius * 2.0, self.radius * 2.0)
+    }
+
+    /// Check if the point is inside or on the edge of the circle.
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.center.sqr_dist(p) <= self.radius * self.radius
+    }
+
+    /// Check if the two circles overlap or touch.
+    pub fn overlaps(&self, c: &Self) -> bool {
+        let r = self.radius + c.radius;
+        self.center.sqr_dist(c.center) <= r * r
+    }
+
+    /// Check if the circle overlaps or touches the rectangle.
+    pub fn overlaps_rect(&self, r: &Rect) -> bool {
+        self.contains(self.center.clamp(r.min(), r.max()))
+    }
+
+    /// Find where the infinite line passing through `a` and `b` crosses the edge of the circle,
+    /// ordered from `a` towards `b`. If the line only touches the circle, both points are the same.
+    pub fn intersect_line(&self, a: Vec2, b: Vec2) -> Option<(Vec2, Vec2)> {
+        let d = b - a;
+        let f = a - self.center;
+        let qa = d.dot(d);
+        let qb = 2.0 * f.dot(d);
+        let qc = f.dot(f) - self.radius * self.radius;
+        let disc = qb * qb - 4.0 * qa * qc;
+        if qa == 0.0 || disc < 0.0 {
+            return None;
+        }
+        let disc = disc.sqrt();
+        let t1 = (-qb - disc) / (2.0 * qa);
+        let t2 = (-qb + disc) / (2.0 * qa);
+        Some((a + d * t1, a + d * t2))
+    }
+}
+
+impl AsRef<[f32]> for Circle {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 3) }
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.center, self.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rect, vec2};
+
+    #[test]
+    fn overlaps_touches_and_intersects() {
+        let a = circle(Vec2::ZERO, 5.0);
+        assert!(a.overlaps(&circle(vec2(8.0, 0.0), 4.0)));
+        assert!(!a.overlaps(&circle(vec2(8.0, 0.0), 2.0)));
+        assert!(a.contains(vec2(3.0, 4.0)));
+
+        assert!(a.overlaps_rect(&rect(3.0, 4.0, 10.0, 10.0)));
+        assert!(!a.overlaps_rect(&rect(3.5, 4.0, 10.0, 10.0)));
+        assert!(a.bounding_rect().approx_eq(&rect(-5.0, -5.0, 10.0, 10.0)));
+
+        let (p, q) = a.intersect_line(vec2(-10.0, 3.0), vec2(10.0, 3.0)).unwrap();
+        assert!(p.approx_eq(&vec2(-4.0, 3.0)));
+        assert!(q.approx_eq(&vec2(4.0, 3.0)));
+        assert_eq!(a.intersect_line(vec2(-10.0, 6.0), vec2(10.0, 6.0)), None);
+    }
+}
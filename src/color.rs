@@ -1,17 +1,28 @@
-use crate::Vec4;
-use serde::de::{Error, Visitor};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
-use std::ops::{
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Approx, Vec4};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
     Index, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign,
 };
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::de::{Error, Unexpected, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// A 32-bit RGBA color, with 8-bits per channel.
 #[repr(C)]
 #[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -19,6 +30,39 @@ pub struct Color {
     pub a: u8,
 }
 
+/// How a source color is combined with the backdrop it is drawn over. See
+/// [Color::blend()](struct.Color.html#method.blend).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Add,
+    Subtract,
+    Darken,
+    Lighten,
+}
+
+impl BlendMode {
+    fn apply(self, b: f32, s: f32) -> f32 {
+        match self {
+            BlendMode::Normal => s,
+            BlendMode::Multiply => b * s,
+            BlendMode::Screen => b + s - b * s,
+            BlendMode::Overlay => match b <= 0.5 {
+                true => 2.0 * b * s,
+                false => 1.0 - 2.0 * (1.0 - b) * (1.0 - s),
+            },
+            BlendMode::Add => (b + s).min(1.0),
+            BlendMode::Subtract => (b - s).max(0.0),
+            BlendMode::Darken => b.min(s),
+            BlendMode::Lighten => b.max(s),
+        }
+    }
+}
+
 impl Color {
     /// 0x00000000
     pub const TRANSPARENT: Self = Self {
@@ -92,20 +136,128 @@ impl Color {
     };
 
     /// Construct a color from RGBA components.
-    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
 
     /// Construct a fully-opaque color from RGB components.
-    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
         Self::rgba(r, g, b, 255)
     }
 
-    /// Pack the color into an RGBA hexadecimal value.
+    /// Construct a random, fully-opaque color.
+    #[cfg(feature = "rand")]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::rgb(rng.gen(), rng.gen(), rng.gen())
+    }
+
+    /// Pack the color into a `0xRRGGBBAA` value, with `r` in the high byte.
     pub fn packed(self) -> u32 {
         self.into()
     }
 
+    /// Pack the color into a `0xAARRGGBB` value, with `a` in the high byte.
+    pub fn to_argb_u32(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.r, self.g, self.b])
+    }
+
+    /// Unpack a color from a `0xAARRGGBB` value, with `a` in the high byte.
+    pub fn from_argb_u32(val: u32) -> Self {
+        let [a, r, g, b] = val.to_be_bytes();
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Pack the color into a `0xBBGGRRAA` value, with `b` in the high byte.
+    pub fn to_bgra_u32(&self) -> u32 {
+        u32::from_be_bytes([self.b, self.g, self.r, self.a])
+    }
+
+    /// Unpack a color from a `0xBBGGRRAA` value, with `b` in the high byte.
+    pub fn from_bgra_u32(val: u32) -> Self {
+        let [b, g, r, a] = val.to_be_bytes();
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Pack the color into a `0xAABBGGRR` value, with `a` in the high byte.
+    pub fn to_abgr_u32(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.b, self.g, self.r])
+    }
+
+    /// Unpack a color from a `0xAABBGGRR` value, with `a` in the high byte.
+    pub fn from_abgr_u32(val: u32) -> Self {
+        let [a, b, g, r] = val.to_be_bytes();
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Reduce each component to the given number of bits, dropping the low bits, then expand
+    /// it back to 8 bits so the lowest and highest levels still map to 0 and 255. A depth of 0
+    /// sets the component to 255, and 8 or more leaves it unchanged.
+    pub fn quantize(&self, r_bits: u8, g_bits: u8, b_bits: u8, a_bits: u8) -> Color {
+        fn quantize(c: u8, bits: u8) -> u8 {
+            match bits {
+                0 => 255,
+                1..=7 => expand_bits(c >> (8 - bits), bits),
+                _ => c,
+            }
+        }
+        Self::rgba(
+            quantize(self.r, r_bits),
+            quantize(self.g, g_bits),
+            quantize(self.b, b_bits),
+            quantize(self.a, a_bits),
+        )
+    }
+
+    /// Pack the color into a 16-bit `RGB565` value, with 5 bits of red in the high bits, then
+    /// 6 bits of green and 5 bits of blue. Alpha is dropped.
+    pub fn to_rgb565(&self) -> u16 {
+        (self.r as u16 >> 3) << 11 | (self.g as u16 >> 2) << 5 | (self.b as u16 >> 3)
+    }
+
+    /// Unpack an opaque color from a 16-bit `RGB565` value.
+    pub fn from_rgb565(v: u16) -> Self {
+        Self::rgb(
+            expand_bits((v >> 11) as u8 & 0x1f, 5),
+            expand_bits((v >> 5) as u8 & 0x3f, 6),
+            expand_bits(v as u8 & 0x1f, 5),
+        )
+    }
+
+    /// Look up a [CSS named color](https://www.w3.org/TR/css-color-4/#named-colors), such as
+    /// `"tomato"`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, c)| *c)
+    }
+
+    /// The name of the CSS named color closest to this one, measured in Oklab space.
+    /// Alpha is ignored.
+    pub fn closest_name(&self) -> &'static str {
+        self.nearest_by(&NAMED_COLORS, |(_, c)| *c).0
+    }
+
+    /// Format the color as an `#rrggbbaa` hex string.
+    pub fn to_hex_str(&self) -> String {
+        alloc::format!("#{}", self)
+    }
+
+    /// Parse a color from an `#rrggbbaa` or `#rrggbb` hex string, where the leading `#` is
+    /// optional. Colors without an alpha component are fully-opaque.
+    pub fn from_hex_str(s: &str) -> Option<Self> {
+        let s = s.strip_prefix('#').unwrap_or(s);
+        if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let val = u32::from_str_radix(s, 16).ok()?;
+        match s.len() {
+            8 => Some(Self::from(val)),
+            6 => Some(Self::from(val << 8 | 0xff)),
+            _ => None,
+        }
+    }
+
     /// Construct a color from RGBA floating-point components in range (0.0 - 1.0).
     pub fn rgba_f32(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self {
@@ -193,17 +345,145 @@ impl Color {
         (h, s, v)
     }
 
+    /// Convert an HSL color to RGBA, with the provided alpha.
+    ///
+    /// `h`: hue in degrees
+    /// `s`: saturation (0 - 1)
+    /// `l`: lightness (0 - 1)
+    pub fn from_hsla(h: f32, s: f32, l: f32, a: u8) -> Self {
+        let h = ((h % 360.0) + 360.0) % 360.0 / 60.0;
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h % 2.0) - 1.0).abs());
+        let m = l - c / 2.0;
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let f = |v: f32| ((v + m) * 255.0).round() as u8;
+        Self::rgba(f(r), f(g), f(b), a)
+    }
+
+    /// Convert a fully-opaque HSL color to RGBA.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        Self::from_hsla(h, s, l, 255)
+    }
+
+    /// Convert to hue-saturation-lightness color space.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b, _) = self.floats();
+
+        let min = r.min(g.min(b));
+        let max = r.max(g.max(b));
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+        let s = match delta == 0.0 {
+            true => 0.0,
+            false => delta / (1.0 - (2.0 * l - 1.0).abs()),
+        };
+        let (h, _, _) = self.to_hsv();
+
+        (h, s, l)
+    }
+
+    /// The color with its RGB components inverted, leaving alpha unchanged.
+    pub fn inverted(&self) -> Self {
+        self.map_rgb(|c| 255 - c)
+    }
+
+    /// The color with its alpha replaced.
+    pub fn with_alpha(&self, a: u8) -> Self {
+        Self::rgba(self.r, self.g, self.b, a)
+    }
+
+    /// Increase the HSL lightness of the color by `amount` (0 - 1).
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsla(h, s, (l + amount).clamp(0.0, 1.0), self.a)
+    }
+
+    /// Decrease the HSL lightness of the color by `amount` (0 - 1).
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Increase the HSL saturation of the color by `amount` (0 - 1).
+    pub fn saturate(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsla(h, (s + amount).clamp(0.0, 1.0), l, self.a)
+    }
+
+    /// Decrease the HSL saturation of the color by `amount` (0 - 1).
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Rotate the HSL hue of the color by `degrees`, wrapping around the color wheel.
+    pub fn rotate_hue(&self, degrees: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsla(h + degrees, s, l, self.a)
+    }
+
+    /// The color opposite this one on the color wheel.
+    pub fn complementary(&self) -> Self {
+        self.rotate_hue(180.0)
+    }
+
+    /// This color followed by the two colors evenly spaced from it around the color wheel.
+    pub fn triadic(&self) -> [Self; 3] {
+        [*self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// Approximate the color of a blackbody radiator at the given temperature in Kelvin,
+    /// clamped to the range (1000 - 40000), using
+    /// [Tanner Helland's](https://tannerhelland.com/2012/09/18/convert-temperature-rgb-algorithm-code.html)
+    /// curve fit. Daylight is around 6500K, candlelight around 2000K.
+    pub fn from_temperature(kelvin: f32) -> Self {
+        let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+        let r = match t <= 66.0 {
+            true => 255.0,
+            false => 329.69873 * (t - 60.0).powf(-0.13320476),
+        };
+        let g = match t <= 66.0 {
+            true => 99.4708 * t.ln() - 161.11957,
+            false => 288.12216 * (t - 60.0).powf(-0.07551485),
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.51773 * (t - 10.0).ln() - 305.0448
+        };
+        let f = |v: f32| v.clamp(0.0, 255.0).round() as u8;
+        Self::rgb(f(r), f(g), f(b))
+    }
+
+    /// Convert to linear RGBA, decoding the sRGB gamma of the color channels.
+    /// Alpha is already linear, so it is left as-is.
+    pub fn to_linear(&self) -> Vec4 {
+        let (r, g, b, a) = self.floats();
+        crate::vec4(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    /// Convert from linear RGBA, encoding the color channels with sRGB gamma.
+    pub fn from_linear(v: Vec4) -> Self {
+        Self::rgba_f32(
+            linear_to_srgb(v.x),
+            linear_to_srgb(v.y),
+            linear_to_srgb(v.z),
+            v.w,
+        )
+    }
+
     /// Convert to [CIE 1931](https://en.wikipedia.org/wiki/CIE_1931_color_space) XYZ color space.
     pub fn to_xyz(&self) -> (f32, f32, f32) {
-        fn comp(r: f32) -> f32 {
-            if r <= 0.04045 {
-                r / 12.92
-            } else {
-                ((r + 0.055) / 1.055).powf(2.4)
-            }
-        }
         let (r, g, b, _) = self.floats();
-        let (r, g, b) = (comp(r), comp(g), comp(b));
+        let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
         (
             (0.4124 * r + 0.3576 * g + 0.1805 * b) * 100.0,
             (0.2126 * r + 0.7152 * g + 0.0722 * b) * 100.0,
@@ -217,27 +497,13 @@ impl Color {
         let r = x * 3.2404542 + y * -1.5371385 + z * -0.4985314;
         let g = x * -0.9692660 + y * 1.8760108 + z * 0.0415560;
         let b = x * 0.0556434 + y * -0.2040259 + z * 1.0572252;
-        fn comp(r: f32) -> f32 {
-            if r > 0.0031308 {
-                1.055 * r.powf(1.0 / 2.4) - 0.055
-            } else {
-                12.92 * r
-            }
-        }
-        Self::rgb_f32(comp(r), comp(g), comp(b))
+        Self::rgb_f32(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
     }
 
     /// Convert to [OKLab](https://bottosson.github.io/posts/oklab) color space.
     pub fn to_oklab(&self) -> (f32, f32, f32) {
-        fn comp(r: f32) -> f32 {
-            if r > 0.04045 {
-                ((r + 0.055) / 1.055).powf(2.4)
-            } else {
-                r / 12.92
-            }
-        }
         let (r, g, b, _) = self.floats();
-        let (r, g, b) = (comp(r), comp(g), comp(b));
+        let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
         let l = (0.4121656120 * r + 0.5362752080 * g + 0.0514575653 * b).cbrt();
         let m = (0.2118591070 * r + 0.6807189584 * g + 0.1074065790 * b).cbrt();
         let s = (0.0883097947 * r + 0.2818474174 * g + 0.6302613616 * b).cbrt();
@@ -311,14 +577,64 @@ impl Color {
         let r = 4.0767245293 * l - 3.3072168827 * m + 0.2307590544 * s;
         let g = -1.2681437731 * l + 2.6093323231 * m - 0.3411344290 * s;
         let b = -0.0041119885 * l - 0.7034763098 * m + 1.7068625689 * s;
-        fn comp(r: f32) -> f32 {
-            if r > 0.0031308 {
-                1.055 * r.powf(2.4) - 0.055
-            } else {
-                12.92 * r
+        Self::rgb_f32(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+    }
+
+    /// The [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between
+    /// two colors, from 1.0 (identical luminance) up to 21.0 (black and white).
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.to_xyz().1 / 100.0;
+        let b = other.to_xyz().1 / 100.0;
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// If the contrast between two colors meets the WCAG AA threshold for readable text,
+    /// which is 4.5 for normal text and 3.0 for large text.
+    pub fn meets_wcag_aa(&self, other: &Color, large_text: bool) -> bool {
+        let min = match large_text {
+            true => 3.0,
+            false => 4.5,
+        };
+        self.contrast_ratio(other) >= min
+    }
+
+    /// The [CIE76](https://en.wikipedia.org/wiki/Color_difference#CIE76) color difference,
+    /// which is the distance between both colors in CIELAB space. Alpha is ignored.
+    pub fn delta_e_cie76(&self, other: &Color) -> f32 {
+        let (l1, a1, b1) = self.to_cielab();
+        let (l2, a2, b2) = other.to_cielab();
+        ((l1 - l2) * (l1 - l2) + (a1 - a2) * (a1 - a2) + (b1 - b2) * (b1 - b2)).sqrt()
+    }
+
+    /// The distance between both colors in OKLab space. Alpha is ignored.
+    pub fn delta_e_oklab(&self, other: &Color) -> f32 {
+        sqr_dist_oklab(self.to_oklab(), other.to_oklab()).sqrt()
+    }
+
+    /// The color in `palette` perceptually closest to this one, measured in OKLab space.
+    /// Panics if `palette` is empty.
+    pub fn nearest_in<'a>(&self, palette: &'a [Color]) -> &'a Color {
+        self.nearest_by(palette, |c| *c)
+    }
+
+    /// The item in `items` whose color is closest to this one in OKLab space.
+    fn nearest_by<'a, T>(&self, items: &'a [T], color: impl Fn(&T) -> Color) -> &'a T {
+        let lab = self.to_oklab();
+        let mut best = (&items[0], f32::INFINITY);
+        for item in items {
+            let dist = sqr_dist_oklab(lab, color(item).to_oklab());
+            if dist < best.1 {
+                best = (item, dist);
             }
         }
-        Self::rgb_f32(comp(r), comp(g), comp(b))
+        best.0
+    }
+
+    /// Check if two colors are approximately equal, comparing their floating-point components.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        let a: Vec4 = (*self).into();
+        let b: Vec4 = (*other).into();
+        a.approx(&b)
     }
 
     /// Linearly interpolate between two colors by a factor `t`.
@@ -360,6 +676,50 @@ impl Color {
         }
     }
 
+    /// Draw this color over the `backdrop`, combining their RGB components with the blend
+    /// `mode` and then compositing the result with [source-over](https://www.w3.org/TR/compositing-1/#blending)
+    /// alpha blending.
+    pub fn blend(self, backdrop: Color, mode: BlendMode) -> Color {
+        let (sr, sg, sb, sa) = self.floats();
+        let (br, bg, bb, ba) = backdrop.floats();
+        let a = sa + ba * (1.0 - sa);
+        if a == 0.0 {
+            return Self::rgba(0, 0, 0, 0);
+        }
+        let channel = |s: f32, b: f32| {
+            let s = (1.0 - ba) * s + ba * mode.apply(b, s);
+            let c = (sa * s + ba * b * (1.0 - sa)) / a;
+            (c * 255.0).round() as u8
+        };
+        Self::rgba(
+            channel(sr, br),
+            channel(sg, bg),
+            channel(sb, bb),
+            (a * 255.0).round() as u8,
+        )
+    }
+
+    /// Retrieve the RGBA components as an array of floating-point values in range (0.0 - 1.0).
+    pub fn to_array_f32(&self) -> [f32; 4] {
+        let (r, g, b, a) = self.floats();
+        [r, g, b, a]
+    }
+
+    /// Construct a color from an array of RGBA floating-point components in range (0.0 - 1.0).
+    pub fn from_array_f32(a: [f32; 4]) -> Self {
+        Self::rgba_f32(a[0], a[1], a[2], a[3])
+    }
+
+    /// Apply a function to each of the RGBA components.
+    pub fn map(&self, f: impl Fn(u8) -> u8) -> Self {
+        Self::rgba(f(self.r), f(self.g), f(self.b), f(self.a))
+    }
+
+    /// Apply a function to each of the RGB components, leaving alpha unchanged.
+    pub fn map_rgb(&self, f: impl Fn(u8) -> u8) -> Self {
+        Self::rgba(f(self.r), f(self.g), f(self.b), self.a)
+    }
+
     /// Retrieve the RGBA components as floating-point values in range (0.0 - 1.0).
     pub fn floats(self) -> (f32, f32, f32, f32) {
         (
@@ -371,8 +731,258 @@ impl Color {
     }
 }
 
+/// A color gradient made up of color stops at positions along it.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+}
+
+impl Gradient {
+    /// Create a gradient from `(position, color)` stops, which are sorted by position. Stops
+    /// with a NaN position are dropped.
+    pub fn new(mut stops: Vec<(f32, Color)>) -> Self {
+        stops.retain(|s| !s.0.is_nan());
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops }
+    }
+
+    /// The color stops, sorted by position.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Sample the color at position `t`, interpolating between the two stops around it in
+    /// sRGB space. Positions outside the stops are clamped to the first or last color, a NaN
+    /// position gives the first color, and an empty gradient is transparent.
+    pub fn sample(&self, t: f32) -> Color {
+        self.sample_with(t, |a, b, t| a.lerp(b, t))
+    }
+
+    /// Same as [sample()](#method.sample), but interpolating in
+    /// [Oklab](https://bottosson.github.io/posts/oklab/) space, which keeps the perceived
+    /// lightness more even between stops.
+    pub fn sample_oklab(&self, t: f32) -> Color {
+        self.sample_with(t, |a, b, t| {
+            let (l1, a1, b1) = a.to_oklab();
+            let (l2, a2, b2) = b.to_oklab();
+            let alpha = crate::lerp(a.a as f32, b.a as f32, t) as u8;
+            Color::from_oklab(
+                crate::lerp(l1, l2, t),
+                crate::lerp(a1, a2, t),
+                crate::lerp(b1, b2, t),
+            )
+            .with_alpha(alpha)
+        })
+    }
+
+    fn sample_with(&self, t: f32, lerp: impl Fn(Color, Color, f32) -> Color) -> Color {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Color::TRANSPARENT,
+        };
+        if t.is_nan() || t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+        let i = self
+            .stops
+            .iter()
+            .position(|s| s.0 > t)
+            .unwrap_or(self.stops.len() - 1);
+        let (p0, c0) = self.stops[i - 1];
+        let (p1, c1) = self.stops[i];
+        lerp(c0, c1, (t - p0) / (p1 - p0))
+    }
+}
+
+/// The [CSS named colors](https://www.w3.org/TR/css-color-4/#named-colors), in alphabetical order.
+static NAMED_COLORS: [(&str, Color); 148] = [
+    ("aliceblue", Color::rgb(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", Color::rgb(0xfa, 0xeb, 0xd7)),
+    ("aqua", Color::rgb(0x00, 0xff, 0xff)),
+    ("aquamarine", Color::rgb(0x7f, 0xff, 0xd4)),
+    ("azure", Color::rgb(0xf0, 0xff, 0xff)),
+    ("beige", Color::rgb(0xf5, 0xf5, 0xdc)),
+    ("bisque", Color::rgb(0xff, 0xe4, 0xc4)),
+    ("black", Color::rgb(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Color::rgb(0xff, 0xeb, 0xcd)),
+    ("blue", Color::rgb(0x00, 0x00, 0xff)),
+    ("blueviolet", Color::rgb(0x8a, 0x2b, 0xe2)),
+    ("brown", Color::rgb(0xa5, 0x2a, 0x2a)),
+    ("burlywood", Color::rgb(0xde, 0xb8, 0x87)),
+    ("cadetblue", Color::rgb(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", Color::rgb(0x7f, 0xff, 0x00)),
+    ("chocolate", Color::rgb(0xd2, 0x69, 0x1e)),
+    ("coral", Color::rgb(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", Color::rgb(0x64, 0x95, 0xed)),
+    ("cornsilk", Color::rgb(0xff, 0xf8, 0xdc)),
+    ("crimson", Color::rgb(0xdc, 0x14, 0x3c)),
+    ("cyan", Color::rgb(0x00, 0xff, 0xff)),
+    ("darkblue", Color::rgb(0x00, 0x00, 0x8b)),
+    ("darkcyan", Color::rgb(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", Color::rgb(0xb8, 0x86, 0x0b)),
+    ("darkgray", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", Color::rgb(0x00, 0x64, 0x00)),
+    ("darkgrey", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", Color::rgb(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", Color::rgb(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", Color::rgb(0x55, 0x6b, 0x2f)),
+    ("darkorange", Color::rgb(0xff, 0x8c, 0x00)),
+    ("darkorchid", Color::rgb(0x99, 0x32, 0xcc)),
+    ("darkred", Color::rgb(0x8b, 0x00, 0x00)),
+    ("darksalmon", Color::rgb(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", Color::rgb(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", Color::rgb(0x48, 0x3d, 0x8b)),
+    ("darkslategray", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", Color::rgb(0x00, 0xce, 0xd1)),
+    ("darkviolet", Color::rgb(0x94, 0x00, 0xd3)),
+    ("deeppink", Color::rgb(0xff, 0x14, 0x93)),
+    ("deepskyblue", Color::rgb(0x00, 0xbf, 0xff)),
+    ("dimgray", Color::rgb(0x69, 0x69, 0x69)),
+    ("dimgrey", Color::rgb(0x69, 0x69, 0x69)),
+    ("dodgerblue", Color::rgb(0x1e, 0x90, 0xff)),
+    ("firebrick", Color::rgb(0xb2, 0x22, 0x22)),
+    ("floralwhite", Color::rgb(0xff, 0xfa, 0xf0)),
+    ("forestgreen", Color::rgb(0x22, 0x8b, 0x22)),
+    ("fuchsia", Color::rgb(0xff, 0x00, 0xff)),
+    ("gainsboro", Color::rgb(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", Color::rgb(0xf8, 0xf8, 0xff)),
+    ("gold", Color::rgb(0xff, 0xd7, 0x00)),
+    ("goldenrod", Color::rgb(0xda, 0xa5, 0x20)),
+    ("gray", Color::rgb(0x80, 0x80, 0x80)),
+    ("green", Color::rgb(0x00, 0x80, 0x00)),
+    ("greenyellow", Color::rgb(0xad, 0xff, 0x2f)),
+    ("grey", Color::rgb(0x80, 0x80, 0x80)),
+    ("honeydew", Color::rgb(0xf0, 0xff, 0xf0)),
+    ("hotpink", Color::rgb(0xff, 0x69, 0xb4)),
+    ("indianred", Color::rgb(0xcd, 0x5c, 0x5c)),
+    ("indigo", Color::rgb(0x4b, 0x00, 0x82)),
+    ("ivory", Color::rgb(0xff, 0xff, 0xf0)),
+    ("khaki", Color::rgb(0xf0, 0xe6, 0x8c)),
+    ("lavender", Color::rgb(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", Color::rgb(0xff, 0xf0, 0xf5)),
+    ("lawngreen", Color::rgb(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", Color::rgb(0xff, 0xfa, 0xcd)),
+    ("lightblue", Color::rgb(0xad, 0xd8, 0xe6)),
+    ("lightcoral", Color::rgb(0xf0, 0x80, 0x80)),
+    ("lightcyan", Color::rgb(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", Color::rgb(0xfa, 0xfa, 0xd2)),
+    ("lightgray", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", Color::rgb(0x90, 0xee, 0x90)),
+    ("lightgrey", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightpink", Color::rgb(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", Color::rgb(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", Color::rgb(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", Color::rgb(0x87, 0xce, 0xfa)),
+    ("lightslategray", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightslategrey", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Color::rgb(0xb0, 0xc4, 0xde)),
+    ("lightyellow", Color::rgb(0xff, 0xff, 0xe0)),
+    ("lime", Color::rgb(0x00, 0xff, 0x00)),
+    ("limegreen", Color::rgb(0x32, 0xcd, 0x32)),
+    ("linen", Color::rgb(0xfa, 0xf0, 0xe6)),
+    ("magenta", Color::rgb(0xff, 0x00, 0xff)),
+    ("maroon", Color::rgb(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Color::rgb(0x66, 0xcd, 0xaa)),
+    ("mediumblue", Color::rgb(0x00, 0x00, 0xcd)),
+    ("mediumorchid", Color::rgb(0xba, 0x55, 0xd3)),
+    ("mediumpurple", Color::rgb(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", Color::rgb(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", Color::rgb(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", Color::rgb(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", Color::rgb(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", Color::rgb(0xc7, 0x15, 0x85)),
+    ("midnightblue", Color::rgb(0x19, 0x19, 0x70)),
+    ("mintcream", Color::rgb(0xf5, 0xff, 0xfa)),
+    ("mistyrose", Color::rgb(0xff, 0xe4, 0xe1)),
+    ("moccasin", Color::rgb(0xff, 0xe4, 0xb5)),
+    ("navajowhite", Color::rgb(0xff, 0xde, 0xad)),
+    ("navy", Color::rgb(0x00, 0x00, 0x80)),
+    ("oldlace", Color::rgb(0xfd, 0xf5, 0xe6)),
+    ("olive", Color::rgb(0x80, 0x80, 0x00)),
+    ("olivedrab", Color::rgb(0x6b, 0x8e, 0x23)),
+    ("orange", Color::rgb(0xff, 0xa5, 0x00)),
+    ("orangered", Color::rgb(0xff, 0x45, 0x00)),
+    ("orchid", Color::rgb(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", Color::rgb(0xee, 0xe8, 0xaa)),
+    ("palegreen", Color::rgb(0x98, 0xfb, 0x98)),
+    ("paleturquoise", Color::rgb(0xaf, 0xee, 0xee)),
+    ("palevioletred", Color::rgb(0xdb, 0x70, 0x93)),
+    ("papayawhip", Color::rgb(0xff, 0xef, 0xd5)),
+    ("peachpuff", Color::rgb(0xff, 0xda, 0xb9)),
+    ("peru", Color::rgb(0xcd, 0x85, 0x3f)),
+    ("pink", Color::rgb(0xff, 0xc0, 0xcb)),
+    ("plum", Color::rgb(0xdd, 0xa0, 0xdd)),
+    ("powderblue", Color::rgb(0xb0, 0xe0, 0xe6)),
+    ("purple", Color::rgb(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Color::rgb(0x66, 0x33, 0x99)),
+    ("red", Color::rgb(0xff, 0x00, 0x00)),
+    ("rosybrown", Color::rgb(0xbc, 0x8f, 0x8f)),
+    ("royalblue", Color::rgb(0x41, 0x69, 0xe1)),
+    ("saddlebrown", Color::rgb(0x8b, 0x45, 0x13)),
+    ("salmon", Color::rgb(0xfa, 0x80, 0x72)),
+    ("sandybrown", Color::rgb(0xf4, 0xa4, 0x60)),
+    ("seagreen", Color::rgb(0x2e, 0x8b, 0x57)),
+    ("seashell", Color::rgb(0xff, 0xf5, 0xee)),
+    ("sienna", Color::rgb(0xa0, 0x52, 0x2d)),
+    ("silver", Color::rgb(0xc0, 0xc0, 0xc0)),
+    ("skyblue", Color::rgb(0x87, 0xce, 0xeb)),
+    ("slateblue", Color::rgb(0x6a, 0x5a, 0xcd)),
+    ("slategray", Color::rgb(0x70, 0x80, 0x90)),
+    ("slategrey", Color::rgb(0x70, 0x80, 0x90)),
+    ("snow", Color::rgb(0xff, 0xfa, 0xfa)),
+    ("springgreen", Color::rgb(0x00, 0xff, 0x7f)),
+    ("steelblue", Color::rgb(0x46, 0x82, 0xb4)),
+    ("tan", Color::rgb(0xd2, 0xb4, 0x8c)),
+    ("teal", Color::rgb(0x00, 0x80, 0x80)),
+    ("thistle", Color::rgb(0xd8, 0xbf, 0xd8)),
+    ("tomato", Color::rgb(0xff, 0x63, 0x47)),
+    ("turquoise", Color::rgb(0x40, 0xe0, 0xd0)),
+    ("violet", Color::rgb(0xee, 0x82, 0xee)),
+    ("wheat", Color::rgb(0xf5, 0xde, 0xb3)),
+    ("white", Color::rgb(0xff, 0xff, 0xff)),
+    ("whitesmoke", Color::rgb(0xf5, 0xf5, 0xf5)),
+    ("yellow", Color::rgb(0xff, 0xff, 0x00)),
+    ("yellowgreen", Color::rgb(0x9a, 0xcd, 0x32)),
+];
+
+/// The square distance between two colors in OKLab space.
+fn sqr_dist_oklab((l1, a1, b1): (f32, f32, f32), (l2, a2, b2): (f32, f32, f32)) -> f32 {
+    (l1 - l2) * (l1 - l2) + (a1 - a2) * (a1 - a2) + (b1 - b2) * (b1 - b2)
+}
+
+/// Expand a value of `bits` bits to 8 bits by repeating its bit pattern into the low bits.
+fn expand_bits(v: u8, bits: u8) -> u8 {
+    let mut out = 0u32;
+    let mut filled = 0;
+    while filled < 8 {
+        out = out << bits | v as u32;
+        filled += bits;
+    }
+    (out >> (filled - 8)) as u8
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c > 0.0031308 {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * c
+    }
+}
+
 impl Display for Color {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let hex: u32 = (*self).into();
         write!(f, "{:08x}", hex)
     }
@@ -380,7 +990,7 @@ impl Display for Color {
 
 impl PartialOrd for Color {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.packed().partial_cmp(&other.packed())
+        Some(self.cmp(other))
     }
 }
 
@@ -454,7 +1064,7 @@ impl From<Color> for Vec4 {
 
 impl AsRef<[u8]> for Color {
     fn as_ref(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, 4) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const u8, 4) }
     }
 }
 
@@ -636,7 +1246,10 @@ impl Serialize for Color {
     where
         S: Serializer,
     {
-        serializer.serialize_u32(self.packed())
+        match serializer.is_human_readable() {
+            true => serializer.serialize_str(&self.to_hex_str()),
+            false => serializer.serialize_u32(self.packed()),
+        }
     }
 }
 
@@ -646,7 +1259,10 @@ impl<'de> Deserialize<'de> for Color {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_u32(ColorVisitor)
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(ColorVisitor),
+            false => deserializer.deserialize_u32(ColorVisitor),
+        }
     }
 }
 
@@ -657,8 +1273,8 @@ struct ColorVisitor;
 impl<'de> Visitor<'de> for ColorVisitor {
     type Value = Color;
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter.write_str("an unsigned 32-bit integer")
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("an unsigned 32-bit integer or a hex color string")
     }
 
     fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
@@ -667,4 +1283,288 @@ impl<'de> Visitor<'de> for ColorVisitor {
     {
         Ok(Color::from(v))
     }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match v <= u32::MAX as u64 {
+            true => Ok(Color::from(v as u32)),
+            false => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match v >= 0 && v <= u32::MAX as i64 {
+            true => Ok(Color::from(v as u32)),
+            false => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Color::from_hex_str(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast_ratio_extremes() {
+        let ratio = Color::BLACK.contrast_ratio(&Color::WHITE);
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert!((Color::WHITE.contrast_ratio(&Color::BLACK) - ratio).abs() < 1e-4);
+        let grey = Color::rgb(0x80, 0x80, 0x80);
+        assert!(crate::approx_f32(grey.contrast_ratio(&grey), 1.0));
+        assert!(Color::BLACK.meets_wcag_aa(&Color::WHITE, false));
+        assert!(!grey.meets_wcag_aa(&grey, true));
+    }
+
+    #[test]
+    fn const_palette() {
+        const PALETTE: [Color; 2] = [Color::rgb(0, 0, 0), Color::rgba(255, 255, 255, 255)];
+        assert_eq!(PALETTE, [Color::BLACK, Color::WHITE]);
+    }
+
+    #[test]
+    fn linear_round_trip() {
+        for i in 0..=255u8 {
+            let c = Color::rgba(i, 255 - i, i / 2, i);
+            let back = Color::from_linear(c.to_linear());
+            let close = |a: u8, b: u8| (a as i32 - b as i32).abs() <= 1;
+            assert!(close(c.r, back.r) && close(c.g, back.g));
+            assert!(close(c.b, back.b) && close(c.a, back.a));
+        }
+        let mid = Color::rgb(128, 128, 128).to_linear();
+        assert!((mid.x - 0.2158).abs() < 1e-3);
+    }
+
+    #[test]
+    fn map_inverts_and_arrays_round_trip() {
+        let c = Color::rgba(10, 100, 250, 128);
+        assert_eq!(c.map(|c| 255 - c), Color::rgba(245, 155, 5, 127));
+        assert_eq!(c.map_rgb(|c| 255 - c), Color::rgba(245, 155, 5, 128));
+        assert_eq!(Color::from_array_f32(c.to_array_f32()), c);
+        assert_eq!(Color::WHITE.to_array_f32(), [1.0; 4]);
+    }
+
+    #[test]
+    fn invert_and_lighten() {
+        assert_eq!(Color::WHITE.inverted(), Color::BLACK);
+        let faded = Color::WHITE.with_alpha(100);
+        assert_eq!(faded.inverted(), Color::BLACK.with_alpha(100));
+
+        let grey = Color::rgb(128, 128, 128);
+        let light = grey.lighten(0.2);
+        assert!(light.r > grey.r && light.g > grey.g && light.b > grey.b);
+        assert!(light.contrast_ratio(&Color::BLACK) > grey.contrast_ratio(&Color::BLACK));
+        assert!(grey.darken(0.2).r < grey.r);
+        assert_eq!(grey.lighten(2.0), Color::WHITE);
+
+        let red = Color::rgb(200, 80, 80);
+        let (_, s, _) = red.to_hsl();
+        let (_, more, _) = red.saturate(0.2).to_hsl();
+        let (_, less, _) = red.desaturate(0.2).to_hsl();
+        assert!(more > s && less < s);
+    }
+
+    #[test]
+    fn blend_modes() {
+        let c = Color::rgb(30, 140, 220);
+        assert_eq!(c.blend(Color::WHITE, BlendMode::Multiply), c);
+        assert_eq!(c.blend(Color::BLACK, BlendMode::Screen), c);
+        assert_eq!(c.blend(Color::BLACK, BlendMode::Normal), c);
+
+        let overlay = |s: u8, b: u8| {
+            let (s, b) = (s as f32 / 255.0, b as f32 / 255.0);
+            let v = match b <= 0.5 {
+                true => 2.0 * b * s,
+                false => 1.0 - 2.0 * (1.0 - b) * (1.0 - s),
+            };
+            (v * 255.0).round() as u8
+        };
+        let backdrop = Color::rgb(64, 200, 128);
+        let result = c.blend(backdrop, BlendMode::Overlay);
+        assert_eq!(result.r, overlay(30, 64));
+        assert_eq!(result.g, overlay(140, 200));
+        assert_eq!(result.b, overlay(220, 128));
+        assert_eq!(result.a, 255);
+    }
+
+    #[test]
+    fn temperature_white_and_warm() {
+        let white = Color::from_temperature(6500.0);
+        assert!(white.r >= 240 && white.g >= 240 && white.b >= 240);
+        let warm = Color::from_temperature(2000.0);
+        assert!(warm.r > warm.b);
+        assert_eq!(warm.a, 255);
+        assert_eq!(
+            Color::from_temperature(100.0),
+            Color::from_temperature(1000.0)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_hex_string_and_u32() {
+        let c = Color::rgba(0xff, 0x88, 0x00, 0xff);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, "\"#ff8800ff\"");
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), c);
+        assert_eq!(serde_json::from_str::<Color>("4287103231").unwrap(), c);
+
+        let bytes = bincode::serialize(&c).unwrap();
+        assert_eq!(bytes, 0xff8800ffu32.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Color>(&bytes).unwrap(), c);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_signed_integer() {
+        #[derive(Deserialize)]
+        struct Config {
+            c: Color,
+        }
+        let config: Config = toml::from_str("c = 4278190335").unwrap();
+        assert_eq!(config.c, Color::rgba(0xff, 0x00, 0x00, 0xff));
+        let config: Config = toml::from_str("c = \"#ff8800ff\"").unwrap();
+        assert_eq!(config.c, Color::rgba(0xff, 0x88, 0x00, 0xff));
+        assert!(toml::from_str::<Config>("c = -1").is_err());
+        assert!(toml::from_str::<Config>("c = 4294967296").is_err());
+    }
+
+    #[test]
+    fn gradient_three_stops() {
+        let g = Gradient::new(alloc::vec![
+            (1.0, Color::BLUE),
+            (0.0, Color::RED),
+            (0.5, Color::WHITE),
+        ]);
+        assert_eq!(g.sample(0.0), Color::RED);
+        assert_eq!(g.sample(0.25), Color::rgb(255, 127, 127));
+        assert_eq!(g.sample(0.5), Color::WHITE);
+        assert_eq!(g.sample(1.0), Color::BLUE);
+        assert_eq!(g.sample(-1.0), Color::RED);
+        assert_eq!(g.sample(2.0), Color::BLUE);
+        let mid = g.sample_oklab(0.75);
+        assert!(mid.b > mid.r && mid.r > 0);
+        assert_eq!(Gradient::default().sample(0.5), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn gradient_nan_stops_and_positions() {
+        let single = Gradient::new(alloc::vec![(0.5, Color::RED)]);
+        assert_eq!(single.sample(f32::NAN), Color::RED);
+        assert_eq!(single.sample(0.7), Color::RED);
+        let g = Gradient::new(alloc::vec![
+            (f32::NAN, Color::WHITE),
+            (0.0, Color::RED),
+            (1.0, Color::BLUE),
+        ]);
+        assert_eq!(g.stops().len(), 2);
+        assert_eq!(g.sample(f32::NAN), Color::RED);
+        assert_eq!(g.sample(1.0), Color::BLUE);
+        let only_nan = Gradient::new(alloc::vec![(f32::NAN, Color::WHITE)]);
+        assert_eq!(only_nan.sample(0.5), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn named_colors() {
+        assert_eq!(Color::from_name("red"), Some(Color::RED));
+        assert_eq!(
+            Color::from_name("RebeccaPurple"),
+            Some(Color::rgb(0x66, 0x33, 0x99))
+        );
+        assert_eq!(
+            Color::from_name("TOMATO"),
+            Some(Color::rgb(0xff, 0x63, 0x47))
+        );
+        assert_eq!(Color::from_name("notacolor"), None);
+        assert_eq!(Color::from_name(""), None);
+
+        assert_eq!(Color::RED.closest_name(), "red");
+        assert_eq!(Color::rgb(0xfe, 0x64, 0x45).closest_name(), "tomato");
+    }
+
+    #[test]
+    fn delta_e_and_nearest() {
+        let a = Color::rgb(200, 40, 40);
+        let near = Color::rgb(205, 45, 38);
+        let far = Color::rgb(30, 90, 220);
+        assert_eq!(a.delta_e_cie76(&a), 0.0);
+        assert_eq!(a.delta_e_oklab(&a), 0.0);
+        assert!(a.delta_e_cie76(&near) < a.delta_e_cie76(&far));
+        assert!(a.delta_e_oklab(&near) < a.delta_e_oklab(&far));
+        assert_eq!(a.delta_e_oklab(&far), far.delta_e_oklab(&a));
+
+        let palette = [Color::BLACK, Color::WHITE, far, near];
+        assert_eq!(a.nearest_in(&palette), &near);
+        assert_eq!(
+            Color::rgb(250, 250, 245).nearest_in(&palette),
+            &Color::WHITE
+        );
+    }
+
+    #[test]
+    fn packed_byte_orders() {
+        let c = Color::rgba(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(c.packed(), 0x11223344);
+        assert_eq!(c.to_argb_u32(), 0x44112233);
+        assert_eq!(c.to_bgra_u32(), 0x33221144);
+        assert_eq!(c.to_abgr_u32(), 0x44332211);
+        assert_eq!(Color::from_argb_u32(0x44112233), c);
+        assert_eq!(Color::from_bgra_u32(0x33221144), c);
+        assert_eq!(Color::from_abgr_u32(0x44332211), c);
+    }
+
+    #[test]
+    fn rgb565_round_trip_drops_low_bits() {
+        let c = Color::rgb(0b1011_0111, 0b0110_1110, 0b1111_1010);
+        assert_eq!(c.to_rgb565(), 0b10110 << 11 | 0b011011 << 5 | 0b11111);
+        let back = Color::from_rgb565(c.to_rgb565());
+        assert_eq!(back, Color::rgb(0b1011_0101, 0b0110_1101, 0b1111_1111));
+        assert_eq!(back.r >> 3, c.r >> 3);
+        assert_eq!(back.g >> 2, c.g >> 2);
+        assert_eq!(back.b >> 3, c.b >> 3);
+        assert_eq!(Color::from_rgb565(back.to_rgb565()), back);
+        assert_eq!(c.quantize(5, 6, 5, 8), back);
+
+        assert_eq!(Color::from_rgb565(0xffff), Color::WHITE);
+        assert_eq!(Color::from_rgb565(0), Color::BLACK);
+    }
+
+    #[test]
+    fn quantize_bit_depths() {
+        let c = Color::rgba(0x80, 0x7f, 0xff, 0x01);
+        assert_eq!(c.quantize(1, 1, 1, 1), Color::rgba(255, 0, 255, 0));
+        assert_eq!(c.quantize(3, 3, 2, 0), Color::rgba(0x92, 0x6d, 0xff, 0xff));
+        assert_eq!(c.quantize(8, 8, 8, 8), c);
+    }
+
+    #[test]
+    fn hue_rotation_schemes() {
+        assert_eq!(Color::RED.rotate_hue(120.0), Color::GREEN);
+        assert_eq!(Color::RED.rotate_hue(-120.0), Color::BLUE);
+        assert_eq!(Color::RED.rotate_hue(480.0), Color::GREEN);
+        assert_eq!(Color::RED.complementary(), Color::CYAN);
+        assert_eq!(
+            Color::RED.triadic(),
+            [Color::RED, Color::GREEN, Color::BLUE]
+        );
+
+        let c = Color::rgba(200, 100, 50, 77);
+        let r = c.rotate_hue(90.0);
+        assert_eq!(r.a, 77);
+        let (_, s1, l1) = c.to_hsl();
+        let (_, s2, l2) = r.to_hsl();
+        assert!((s1 - s2).abs() < 0.01 && (l1 - l2).abs() < 0.01);
+        assert_eq!(c.rotate_hue(360.0), c);
+    }
 }
@@ -0,0 +1,78 @@
+/// Float functions that live in `std`, routed through `libm` when building without it.
+///
+/// Inherent methods take priority over trait methods, so whenever `std` is linked (including
+/// test builds) this trait is left out, and call sites like `x.sqrt()` read the same either way.
+/// A dependency can still link `std` into a `no_std` build, in which case the trait goes
+/// unused, so it and its imports allow that.
+#[cfg(not(any(feature = "std", test)))]
+#[allow(dead_code)]
+pub(crate) trait Float {
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl Float for f32 {
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    fn cbrt(self) -> Self {
+        libm::cbrtf(self)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+
+    fn exp(self) -> Self {
+        libm::expf(self)
+    }
+
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+
+    fn tan(self) -> Self {
+        libm::tanf(self)
+    }
+
+    fn acos(self) -> Self {
+        libm::acosf(self)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        libm::atan2f(self, x)
+    }
+
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+}
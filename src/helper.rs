@@ -1,6 +1,11 @@
-pub use std::f32::consts::PI;
-pub use std::f32::consts::SQRT_2;
-pub use std::f32::consts::TAU;
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Vec2;
+use alloc::vec::Vec;
+pub use core::f32::consts::PI;
+pub use core::f32::consts::SQRT_2;
+pub use core::f32::consts::TAU;
 
 pub fn rad(deg: f32) -> f32 {
     deg * (PI / 180.0)
@@ -26,10 +31,71 @@ pub fn sign_i32(x: i32) -> i32 {
     }
 }
 
+/// Check if the value is approximately zero, within the same tolerance as `approx_f32`.
+pub fn approx_zero(x: f32) -> bool {
+    crate::approx_f32(x, 0.0)
+}
+
 pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
 
+/// Map `v` from the range `[in_min, in_max]` onto `[out_min, out_max]`. Values outside
+/// the input range map past the ends of the output range.
+pub fn remap(in_min: f32, in_max: f32, out_min: f32, out_max: f32, v: f32) -> f32 {
+    lerp(out_min, out_max, (v - in_min) / (in_max - in_min))
+}
+
+/// Like [remap()](fn.remap.html), but `v` is clamped to the input range first.
+pub fn remap_clamped(in_min: f32, in_max: f32, out_min: f32, out_max: f32, v: f32) -> f32 {
+    remap_ease(in_min, in_max, out_min, out_max, v, |t| t)
+}
+
+/// Like [remap_clamped()](fn.remap_clamped.html), but the parameter in range (0.0 - 1.0) is
+/// passed through `ease` before being mapped, such as [smooth_step()](fn.smooth_step.html).
+pub fn remap_ease(
+    in_min: f32,
+    in_max: f32,
+    out_min: f32,
+    out_max: f32,
+    v: f32,
+    ease: impl Fn(f32) -> f32,
+) -> f32 {
+    let t = ((v - in_min) / (in_max - in_min)).clamp(0.0, 1.0);
+    lerp(out_min, out_max, ease(t))
+}
+
+/// Wrap the value into the range `[min, max)`, so values past one end re-enter from the other.
+/// If the range is empty (`min == max`), this returns `min`.
+pub fn wrap(value: f32, min: f32, max: f32) -> f32 {
+    let range = max - min;
+    if range == 0.0 {
+        return min;
+    }
+    let mut r = (value - min) % range;
+    if r < 0.0 {
+        r += range;
+        if r >= range {
+            r = 0.0;
+        }
+    }
+    min + r
+}
+
+/// Wrap the value into the range `[min, max)`, so values past one end re-enter from the other.
+/// If the range is empty (`min == max`), this returns `min`.
+pub fn wrap_i32(value: i32, min: i32, max: i32) -> i32 {
+    match max - min {
+        0 => min,
+        range => min + (value - min).rem_euclid(range),
+    }
+}
+
+/// Bounce the value back and forth between `0.0` and `length`.
+pub fn ping_pong(t: f32, length: f32) -> f32 {
+    length - (wrap(t, 0.0, length * 2.0) - length).abs()
+}
+
 pub fn bezier3(a: f32, b: f32, c: f32, t: f32) -> f32 {
     a * (1.0 - t) * (1.0 - t) + b * 2.0 * (1.0 - t) * t + c * t * t
 }
@@ -56,6 +122,35 @@ pub fn smooth_step(t: f32) -> f32 {
     t * t * (3.0 - 2.0 * t)
 }
 
+pub fn smoother_step(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Move `current` towards `target` with exponential smoothing, where `lambda` controls how
+/// quickly it closes the gap. Unlike calling `lerp` every frame, the result only depends on
+/// the total time passed, not how it is split up into steps of `dt`.
+pub fn damp(current: f32, target: f32, lambda: f32, dt: f32) -> f32 {
+    lerp(current, target, 1.0 - (-lambda * dt).exp())
+}
+
+/// Move `current` towards `target` with a critically damped spring, as described in Game
+/// Programming Gems 4, updating `velocity` in place. It takes roughly `smooth_time` seconds
+/// to reach the target and never overshoots it.
+pub fn spring(current: f32, target: f32, velocity: &mut f32, smooth_time: f32, dt: f32) -> f32 {
+    let omega = 2.0 / smooth_time.max(0.0001);
+    let x = omega * dt;
+    let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * exp;
+    let result = target + (change + temp) * exp;
+    if (target > current) == (result > target) {
+        *velocity = 0.0;
+        return target;
+    }
+    result
+}
+
 pub fn sqr_distance(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
     let x = x1 - x2;
     let y = y1 - y2;
@@ -68,14 +163,274 @@ pub fn distance(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
     (x * x + y * y).sqrt()
 }
 
-pub fn hash_f32(val: f32) -> i32 {
-    unsafe {
-        let p: *const f32 = &val;
-        *(p as *const i32)
+/// Signed area of the triangle `abc`, positive if `a -> b -> c` turns from +x towards +y.
+pub fn signed_area(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).cross(c - a) * 0.5
+}
+
+/// Which side of the line `a -> b` the point `p` is on, signed the same way as `signed_area`
+/// and zero if `p` lies on the line.
+pub fn point_side(a: Vec2, b: Vec2, p: Vec2) -> f32 {
+    (b - a).cross(p - a)
+}
+
+/// Estimate the length of the cubic bezier curve `a -> d` by summing `samples` straight
+/// segments along it.
+pub fn bezier4_arclen(a: Vec2, b: Vec2, c: Vec2, d: Vec2, samples: usize) -> f32 {
+    let samples = samples.max(1);
+    let mut len = 0.0;
+    let mut prev = a;
+    for i in 1..=samples {
+        let p = a.bezier4(b, c, d, i as f32 / samples as f32);
+        len += prev.dist(p);
+        prev = p;
+    }
+    len
+}
+
+/// Find the point `dist` along the cubic bezier curve `a -> d`, measured by arc-length
+/// rather than `t`, so that evenly spaced distances give evenly spaced points. The curve is
+/// approximated by `samples` straight segments, and `dist` is clamped to its length.
+///
+/// This walks the curve on every call. To sample the same curve many times, build a
+/// [Bezier4ArcLen](struct.Bezier4ArcLen.html) once instead, which stores the lengths and
+/// looks them up.
+pub fn bezier4_at_distance(a: Vec2, b: Vec2, c: Vec2, d: Vec2, samples: usize, dist: f32) -> Vec2 {
+    let samples = samples.max(1);
+    let mut len = 0.0;
+    let mut prev = a;
+    for i in 1..=samples {
+        let t = i as f32 / samples as f32;
+        let p = a.bezier4(b, c, d, t);
+        let seg = prev.dist(p);
+        if len + seg >= dist {
+            let f = match seg > 0.0 {
+                true => ((dist - len) / seg).max(0.0),
+                false => 0.0,
+            };
+            return a.bezier4(b, c, d, t - (1.0 - f) / samples as f32);
+        }
+        len += seg;
+        prev = p;
     }
+    d
+}
+
+/// A lookup table of arc-lengths along the cubic bezier curve `a -> d`, for sampling it at
+/// evenly spaced distances. The curve is walked once when the table is built, and each
+/// lookup after that is a binary search.
+#[derive(Clone, Debug)]
+pub struct Bezier4ArcLen {
+    points: [Vec2; 4],
+    lengths: Vec<f32>,
+}
+
+impl Bezier4ArcLen {
+    /// Build the table by approximating the curve with `samples` straight segments.
+    pub fn new(a: Vec2, b: Vec2, c: Vec2, d: Vec2, samples: usize) -> Self {
+        let samples = samples.max(1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut len = 0.0;
+        let mut prev = a;
+        lengths.push(len);
+        for i in 1..=samples {
+            let p = a.bezier4(b, c, d, i as f32 / samples as f32);
+            len += prev.dist(p);
+            lengths.push(len);
+            prev = p;
+        }
+        Self {
+            points: [a, b, c, d],
+            lengths,
+        }
+    }
+
+    /// The estimated length of the curve, same as [bezier4_arclen()](fn.bezier4_arclen.html).
+    pub fn len(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Find the point `dist` along the curve, same as
+    /// [bezier4_at_distance()](fn.bezier4_at_distance.html). The distance is clamped to the
+    /// length of the curve.
+    pub fn at_distance(&self, dist: f32) -> Vec2 {
+        let [a, b, c, d] = self.points;
+        let i = self.lengths.partition_point(|&len| len < dist);
+        if i == 0 {
+            return a;
+        }
+        if i == self.lengths.len() {
+            return d;
+        }
+        let (prev, next) = (self.lengths[i - 1], self.lengths[i]);
+        let f = match next > prev {
+            true => (dist - prev) / (next - prev),
+            false => 0.0,
+        };
+        let samples = (self.lengths.len() - 1) as f32;
+        a.bezier4(b, c, d, ((i - 1) as f32 + f) / samples)
+    }
+}
+
+/// The bits of the float as an integer, for hashing. `-0.0` is treated as `0.0` and all NaNs
+/// as the same value, so values that compare equal also hash equally.
+pub fn hash_f32(val: f32) -> i32 {
+    let val = match val {
+        _ if val == 0.0 => 0.0,
+        _ if val.is_nan() => f32::NAN,
+        _ => val,
+    };
+    val.to_bits() as i32
 }
 
 /*#[inline]
 pub fn clamp<T: Ord>(val: T, min: T, max: T) -> T {
-    std::cmp::min(std::cmp::max(val, min), max)
+    core::cmp::min(core::cmp::max(val, min), max)
 }*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec2;
+
+    #[test]
+    fn signed_area_winding() {
+        let (a, b, c) = (Vec2::ZERO, vec2(2.0, 0.0), vec2(0.0, 2.0));
+        assert_eq!(signed_area(a, b, c), 2.0);
+        assert_eq!(signed_area(a, c, b), -2.0);
+        assert_eq!(signed_area(a, b, vec2(4.0, 0.0)), 0.0);
+        assert_eq!(a.perp_dot(b), a.cross(b));
+        assert!(point_side(a, b, c) > 0.0);
+        assert!(point_side(b, a, c) < 0.0);
+        assert_eq!(point_side(a, b, vec2(1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn smoother_step_flat_endpoints() {
+        assert_eq!(smooth_step(0.5), 0.5);
+        assert_eq!(smoother_step(0.5), 0.5);
+        let h = 1e-2;
+        for &t in &[0.0, 1.0 - 2.0 * h] {
+            let (f0, f1, f2) = (
+                smoother_step(t),
+                smoother_step(t + h),
+                smoother_step(t + 2.0 * h),
+            );
+            assert!(((f1 - f0) / h).abs() < 0.01);
+            assert!(((f2 - 2.0 * f1 + f0) / (h * h)).abs() < 1.0);
+        }
+        let (f0, f1, f2) = (smooth_step(0.0), smooth_step(h), smooth_step(2.0 * h));
+        assert!(((f2 - 2.0 * f1 + f0) / (h * h)) > 5.0);
+    }
+
+    #[test]
+    fn wrap_and_ping_pong() {
+        assert_eq!(wrap(-0.5, 0.0, 1.0), 0.5);
+        assert_eq!(wrap(7.0, 2.0, 4.0), 3.0);
+        assert_eq!(wrap(3.0, 2.0, 2.0), 2.0);
+        assert_eq!(wrap_i32(-1, 0, 4), 3);
+        assert_eq!(wrap_i32(9, 2, 5), 3);
+        assert_eq!(wrap_i32(9, 2, 2), 2);
+        assert_eq!(ping_pong(1.5, 1.0), 0.5);
+        assert_eq!(ping_pong(2.25, 1.0), 0.25);
+        assert_eq!(ping_pong(3.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn approx_zero_checks() {
+        assert!(approx_zero(0.0) && approx_zero(-f32::EPSILON / 2.0));
+        assert!(!approx_zero(0.01));
+        assert!(!approx_zero(f32::NAN));
+    }
+
+    #[test]
+    fn bezier4_straight_line_length() {
+        let (a, d) = (vec2(1.0, 1.0), vec2(4.0, 5.0));
+        let (b, c) = (a.lerp(d, 0.2), a.lerp(d, 0.9));
+        assert!((bezier4_arclen(a, b, c, d, 64) - 5.0).abs() < 1e-4);
+        let lut = Bezier4ArcLen::new(a, b, c, d, 64);
+        assert!((lut.len() - 5.0).abs() < 1e-4);
+        for &dist in &[0.0, 1.0, 2.5, 4.0, 5.0] {
+            let p = bezier4_at_distance(a, b, c, d, 64, dist);
+            assert!((a.dist(p) - dist).abs() < 1e-3);
+            assert!(lut.at_distance(dist).approx_eq(&p));
+        }
+        assert_eq!(lut.at_distance(-1.0), a);
+        assert_eq!(lut.at_distance(10.0), d);
+    }
+
+    #[test]
+    fn damp_is_frame_rate_independent() {
+        let (lambda, total) = (4.0, 0.5);
+        let big = damp(0.0, 10.0, lambda, total);
+        let (mut small, mut naive) = (0.0, 0.0);
+        let steps = 100;
+        let dt = total / steps as f32;
+        for _ in 0..steps {
+            small = damp(small, 10.0, lambda, dt);
+            naive = lerp(naive, 10.0, lambda * dt);
+        }
+        assert!((small - big).abs() < 1e-3);
+        assert!((lerp(0.0, 10.0, lambda * total) - naive).abs() > 1.0);
+
+        let v = vec2(0.0, 2.0).damp(vec2(10.0, -2.0), lambda, total);
+        assert!(crate::approx_f32(v.x, big));
+    }
+
+    #[test]
+    fn remap_clamped_and_eased() {
+        assert_eq!(remap_clamped(0.0, 10.0, 100.0, 200.0, 2.5), 125.0);
+        assert_eq!(remap_clamped(0.0, 10.0, 100.0, 200.0, -5.0), 100.0);
+        assert_eq!(remap_clamped(0.0, 10.0, 100.0, 200.0, 15.0), 200.0);
+        assert_eq!(remap_clamped(10.0, 0.0, 0.0, 1.0, 2.5), 0.75);
+
+        for v in [-3.0, 0.0, 1.0, 4.5, 7.0, 10.0, 12.0] {
+            let a = remap_ease(0.0, 10.0, -1.0, 1.0, v, |t| t);
+            assert_eq!(a, remap_clamped(0.0, 10.0, -1.0, 1.0, v));
+        }
+
+        assert_eq!(remap_ease(0.0, 10.0, 0.0, 100.0, 5.0, smooth_step), 50.0);
+        assert_eq!(remap_ease(0.0, 10.0, 0.0, 100.0, 2.5, smooth_step), 15.625);
+        assert_eq!(remap_ease(0.0, 10.0, 0.0, 100.0, -1.0, smooth_step), 0.0);
+        assert_eq!(
+            remap_ease(0.0, 10.0, 0.0, 100.0, 11.0, smoother_step),
+            100.0
+        );
+    }
+
+    #[test]
+    fn spring_converges_without_overshoot() {
+        for (start, target) in [(0.0, 10.0), (20.0, 10.0), (-3.0, -3.5)] {
+            let (mut x, mut vel) = (start, 0.0);
+            for _ in 0..300 {
+                let next = spring(x, target, &mut vel, 0.3, 1.0 / 60.0);
+                if target > start {
+                    assert!(next >= x && next <= target);
+                } else {
+                    assert!(next <= x && next >= target);
+                }
+                x = next;
+            }
+            assert!((x - target).abs() < 1e-3);
+        }
+
+        // a strong push towards the target stops on it instead of passing it
+        let mut vel = 100.0;
+        assert_eq!(spring(0.0, 1.0, &mut vel, 0.5, 0.1), 1.0);
+        assert_eq!(vel, 0.0);
+
+        let (mut p, mut v) = (vec2(0.0, 5.0), vec2(0.0, 0.0));
+        for _ in 0..300 {
+            p = p.spring(vec2(4.0, -1.0), &mut v, 0.3, 1.0 / 60.0);
+        }
+        assert!(p.max_abs_diff(vec2(4.0, -1.0)) < 1e-3);
+    }
+
+    #[test]
+    fn wrap_tiny_negative_stays_below_max() {
+        assert_eq!(wrap(-1e-9, 0.0, 1.0), 0.0);
+        assert_eq!(wrap(-1e-9, 2.0, 3.0), 2.0);
+        assert!(wrap(-1e-9, 0.0, 1.0) < 1.0);
+        assert!((wrap(-0.25, 0.0, 1.0) - 0.75).abs() < 1e-6);
+    }
+}
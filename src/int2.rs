@@ -1,21 +1,27 @@
-use std::fmt;
-use std::hash::Hash;
-use std::ops::{
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Int2 {
     pub x: i32,
     pub y: i32,
 }
 
-pub fn int2(x: i32, y: i32) -> Int2 {
+pub const fn int2(x: i32, y: i32) -> Int2 {
     Int2 { x, y }
 }
 
@@ -27,10 +33,18 @@ impl Int2 {
     pub const DOWN: Self = Self { x: 0, y: 1 };
     pub const UP: Self = Self { x: 0, y: -1 };
 
-    pub fn new(x: i32, y: i32) -> Self {
+    pub const fn new(x: i32, y: i32) -> Self {
         int2(x, y)
     }
 
+    pub fn to_array(&self) -> [i32; 2] {
+        [self.x, self.y]
+    }
+
+    pub fn from_array(a: [i32; 2]) -> Self {
+        int2(a[0], a[1])
+    }
+
     pub fn xy_len(&self) -> i32 {
         self.x.abs() + self.y.abs()
     }
@@ -43,6 +57,18 @@ impl Int2 {
         int2(-self.y, self.x)
     }
 
+    /// Rotate by `steps` quarter-turns. Positive steps turn the same way as
+    /// [turn_right()](#method.turn_right), negative steps the same way as
+    /// [turn_left()](#method.turn_left).
+    pub fn rotate_90(&self, steps: i32) -> Self {
+        match steps.rem_euclid(4) {
+            0 => *self,
+            1 => self.turn_right(),
+            2 => -*self,
+            _ => self.turn_left(),
+        }
+    }
+
     pub fn only_x(&self) -> Self {
         int2(self.x, 0)
     }
@@ -55,6 +81,17 @@ impl Int2 {
         int2(self.x.abs(), self.y.abs())
     }
 
+    pub fn snap(&self, step: impl Into<Int2>) -> Self {
+        fn snap(v: i32, step: i32) -> i32 {
+            match step == 0 {
+                true => v,
+                false => (v + step / 2).div_euclid(step) * step,
+            }
+        }
+        let step = step.into();
+        int2(snap(self.x, step.x), snap(self.y, step.y))
+    }
+
     pub fn min(&self, other: Self) -> Self {
         int2(self.x.min(other.x), self.y.min(other.y))
     }
@@ -75,6 +112,71 @@ impl Int2 {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
 
+    pub fn manhattan_dist(&self, other: Self) -> i32 {
+        self.xy_dist(other)
+    }
+
+    pub fn chebyshev_dist(&self, other: Self) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn sqr_dist(&self, other: Self) -> i32 {
+        let x = self.x - other.x;
+        let y = self.y - other.y;
+        x * x + y * y
+    }
+
+    /// Linear interpolation between two points by a factor `t`, rounded to the nearest cell.
+    pub fn lerp(&self, other: Self, t: f32) -> Self {
+        fn lerp(a: i32, b: i32, t: f32) -> i32 {
+            crate::lerp(a as f32, b as f32, t).round() as i32
+        }
+        int2(lerp(self.x, other.x, t), lerp(self.y, other.y, t))
+    }
+
+    /// The four orthogonally adjacent cells, clockwise starting from the right.
+    pub fn neighbors4(&self) -> [Int2; 4] {
+        let p = *self;
+        [
+            p + Self::RIGHT,
+            p + Self::DOWN,
+            p + Self::LEFT,
+            p + Self::UP,
+        ]
+    }
+
+    /// The eight orthogonally and diagonally adjacent cells, clockwise starting from the right.
+    pub fn neighbors8(&self) -> [Int2; 8] {
+        let p = *self;
+        [
+            p + Self::RIGHT,
+            p + Self::RIGHT + Self::DOWN,
+            p + Self::DOWN,
+            p + Self::LEFT + Self::DOWN,
+            p + Self::LEFT,
+            p + Self::LEFT + Self::UP,
+            p + Self::UP,
+            p + Self::RIGHT + Self::UP,
+        ]
+    }
+
+    pub fn line_to(self, end: Int2) -> Int2LineIter {
+        let dx = (end.x - self.x).abs();
+        let dy = -(end.y - self.y).abs();
+        Int2LineIter {
+            pos: self,
+            end,
+            step: int2(
+                crate::sign_i32(end.x - self.x),
+                crate::sign_i32(end.y - self.y),
+            ),
+            dx,
+            dy,
+            err: dx + dy,
+            done: false,
+        }
+    }
+
     pub fn dot(&self, other: Self) -> i32 {
         self.x * other.x + self.y * other.y
     }
@@ -82,6 +184,39 @@ impl Int2 {
     pub fn cross(&self, other: Self) -> i32 {
         self.x * other.y - self.y * other.x
     }
+
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(int2(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, other: Self) -> Option<Self> {
+        Some(int2(
+            self.x.checked_mul(other.x)?,
+            self.y.checked_mul(other.y)?,
+        ))
+    }
+
+    pub fn saturating_add(&self, other: Self) -> Self {
+        int2(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+        )
+    }
+
+    pub fn wrapping_add(&self, other: Self) -> Self {
+        int2(self.x.wrapping_add(other.x), self.y.wrapping_add(other.y))
+    }
+}
+
+impl IntoIterator for Int2 {
+    type Item = i32;
+    type IntoIter = core::array::IntoIter<i32, 2>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.to_array())
+    }
 }
 
 impl fmt::Display for Int2 {
@@ -92,7 +227,7 @@ impl fmt::Display for Int2 {
 
 impl AsRef<[i32]> for Int2 {
     fn as_ref(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const i32, 2) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const i32, 2) }
     }
 }
 
@@ -237,3 +372,159 @@ impl RemAssign<i32> for Int2 {
         *self = self.rem(rhs);
     }
 }
+
+/// Iterates over every point on a Bresenham line between two `Int2`s, inclusive.
+pub struct Int2LineIter {
+    pos: Int2,
+    end: Int2,
+    step: Int2,
+    dx: i32,
+    dy: i32,
+    err: i32,
+    done: bool,
+}
+
+impl Iterator for Int2LineIter {
+    type Item = Int2;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let p = self.pos;
+        if p == self.end {
+            self.done = true;
+        } else {
+            let e2 = self.err * 2;
+            if e2 >= self.dy {
+                self.err += self.dy;
+                self.pos.x += self.step.x;
+            }
+            if e2 <= self.dx {
+                self.err += self.dx;
+                self.pos.y += self.step.y;
+            }
+        }
+        Some(p)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<i32>> for Int2 {
+    fn from(val: mint::Vector2<i32>) -> Self {
+        int2(val.x, val.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Int2> for mint::Vector2<i32> {
+    fn from(val: Int2) -> Self {
+        mint::Vector2 { x: val.x, y: val.y }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::IVec2> for Int2 {
+    fn from(val: glam::IVec2) -> Self {
+        int2(val.x, val.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Int2> for glam::IVec2 {
+    fn from(val: Int2) -> Self {
+        glam::IVec2::new(val.x, val.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_vs_chebyshev() {
+        let a = int2(1, -2);
+        let b = a + int2(3, 4);
+        assert_eq!(a.manhattan_dist(b), 7);
+        assert_eq!(a.chebyshev_dist(b), 4);
+        assert_eq!(a.sqr_dist(b), 25);
+        assert_eq!(b.manhattan_dist(a), 7);
+    }
+
+    #[test]
+    fn line_to_cells() {
+        let diagonal = [int2(0, 0), int2(1, 1), int2(2, 2), int2(3, 3)];
+        assert!(int2(0, 0).line_to(int2(3, 3)).eq(diagonal.iter().copied()));
+        let row = [int2(2, 5), int2(1, 5), int2(0, 5), int2(-1, 5)];
+        assert!(int2(2, 5).line_to(int2(-1, 5)).eq(row.iter().copied()));
+        let column = [int2(0, 0), int2(0, -1), int2(0, -2)];
+        assert!(int2(0, 0).line_to(int2(0, -2)).eq(column.iter().copied()));
+        let steep = [int2(0, 0), int2(0, 1), int2(1, 2), int2(1, 3)];
+        assert!(int2(0, 0).line_to(int2(1, 3)).eq(steep.iter().copied()));
+        assert!(int2(4, 4)
+            .line_to(int2(4, 4))
+            .eq([int2(4, 4)].iter().copied()));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        assert_eq!(int2(7, 12).snap(5), int2(5, 10));
+        assert_eq!(int2(-7, 13).snap(int2(5, 0)), int2(-5, 13));
+        assert_eq!(int2(-8, 8).snap(4), int2(-8, 8));
+    }
+
+    #[test]
+    fn checked_and_wrapping_arithmetic() {
+        assert_eq!(Int2::new(i32::MAX, 0).checked_add(Int2::ONE), None);
+        assert_eq!(int2(1, 2).checked_add(Int2::ONE), Some(int2(2, 3)));
+        assert_eq!(int2(0, 1 << 20).checked_mul(int2(5, 1 << 12)), None);
+        assert_eq!(int2(3, 4).checked_mul(int2(5, 6)), Some(int2(15, 24)));
+        let max = Int2::new(i32::MAX, i32::MIN);
+        assert_eq!(max.saturating_add(int2(1, -1)), max);
+        assert_eq!(max.wrapping_add(int2(1, -1)), int2(i32::MIN, i32::MAX));
+    }
+
+    #[test]
+    fn grid_neighbors() {
+        let n4 = Int2::ZERO.neighbors4();
+        for p in &[int2(1, 0), int2(-1, 0), int2(0, 1), int2(0, -1)] {
+            assert_eq!(n4.iter().filter(|n| *n == p).count(), 1);
+        }
+        let c = int2(5, -3);
+        let n8 = c.neighbors8();
+        assert!(n8.iter().all(|n| c.chebyshev_dist(*n) == 1));
+        assert!(n4.iter().all(|n| n8.iter().any(|m| *m == c + *n)));
+    }
+
+    #[test]
+    fn array_round_trip_and_iteration() {
+        let p = Int2::new(-4, 7);
+        assert_eq!(Int2::from_array(p.to_array()), p);
+        assert!(p.into_iter().eq([-4, 7].iter().copied()));
+    }
+
+    #[test]
+    fn rotate_90_steps() {
+        let p = int2(3, -5);
+        assert_eq!(p.rotate_90(0), p);
+        assert_eq!(p.rotate_90(2), -p);
+        assert_eq!(p.rotate_90(4), p);
+        assert_eq!(p.rotate_90(-4), p);
+        assert_eq!(p.rotate_90(1), p.turn_right());
+        assert_eq!(p.rotate_90(-1), p.turn_left());
+        assert_eq!(p.rotate_90(3), p.turn_left());
+        assert_eq!(p.rotate_90(-7), p.turn_right());
+        assert_eq!(p.rotate_90(1).rotate_90(1), p.rotate_90(2));
+    }
+
+    #[test]
+    fn lerp_rounds_to_nearest_cell() {
+        let (a, b) = (int2(0, 0), int2(10, 0));
+        assert_eq!(a.lerp(b, 0.5), int2(5, 0));
+        assert_eq!(a.lerp(b, 0.04), int2(0, 0));
+        assert_eq!(a.lerp(b, 0.06), int2(1, 0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(int2(-3, 4).lerp(int2(3, -4), 0.5), int2(0, 0));
+        assert_eq!(int2(0, 0).lerp(int2(-10, 7), 0.26), int2(-3, 2));
+    }
+}
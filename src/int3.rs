@@ -1,11 +1,17 @@
-use std::fmt;
-use std::hash::Hash;
-use std::ops::{Add, Div, Mul, Neg, Sub, Rem, RemAssign, Index};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Add, Div, Index, Mul, Neg, Rem, RemAssign, Sub};
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Int3 {
     pub x: i32,
@@ -13,7 +19,7 @@ pub struct Int3 {
     pub z: i32,
 }
 
-pub fn int3(x: i32, y: i32, z: i32) -> Int3 {
+pub const fn int3(x: i32, y: i32, z: i32) -> Int3 {
     Int3 { x, y, z }
 }
 
@@ -27,10 +33,18 @@ impl Int3 {
     pub const FORWARD: Self = Self { x: 0, y: 0, z: 1 };
     pub const BACK: Self = Self { x: 0, y: 0, z: -1 };
 
-    pub fn new(x: i32, y: i32, z: i32) -> Self {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
         int3(x, y, z)
     }
 
+    pub fn to_array(&self) -> [i32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn from_array(a: [i32; 3]) -> Self {
+        int3(a[0], a[1], a[2])
+    }
+
     pub fn xyz_len(&self) -> i32 {
         self.x.abs() + self.y.abs() + self.z.abs()
     }
@@ -82,6 +96,106 @@ impl Int3 {
     pub fn xyz_dist(&self, other: Self) -> i32 {
         (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
     }
+
+    pub fn manhattan_dist(&self, other: Self) -> i32 {
+        self.xyz_dist(other)
+    }
+
+    pub fn chebyshev_dist(&self, other: Self) -> i32 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn sqr_dist(&self, other: Self) -> i32 {
+        let x = self.x - other.x;
+        let y = self.y - other.y;
+        let z = self.z - other.z;
+        x * x + y * y + z * z
+    }
+
+    /// Linear interpolation between two points by a factor `t`, rounded to the nearest cell.
+    pub fn lerp(&self, other: Self, t: f32) -> Self {
+        fn lerp(a: i32, b: i32, t: f32) -> i32 {
+            crate::lerp(a as f32, b as f32, t).round() as i32
+        }
+        int3(
+            lerp(self.x, other.x, t),
+            lerp(self.y, other.y, t),
+            lerp(self.z, other.z, t),
+        )
+    }
+
+    /// The six cells sharing a face with this one.
+    pub fn neighbors6(&self) -> [Int3; 6] {
+        let p = *self;
+        [
+            p + Self::RIGHT,
+            p + Self::LEFT,
+            p + Self::UP,
+            p + Self::DOWN,
+            p + Self::FORWARD,
+            p + Self::BACK,
+        ]
+    }
+
+    /// The 26 cells sharing a face, edge or corner with this one.
+    pub fn neighbors26(&self) -> [Int3; 26] {
+        let mut cells = [*self; 26];
+        let mut i = 0;
+        for z in -1..=1 {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    if x != 0 || y != 0 || z != 0 {
+                        cells[i] = *self + int3(x, y, z);
+                        i += 1;
+                    }
+                }
+            }
+        }
+        cells
+    }
+
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(int3(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, other: Self) -> Option<Self> {
+        Some(int3(
+            self.x.checked_mul(other.x)?,
+            self.y.checked_mul(other.y)?,
+            self.z.checked_mul(other.z)?,
+        ))
+    }
+
+    pub fn saturating_add(&self, other: Self) -> Self {
+        int3(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+            self.z.saturating_add(other.z),
+        )
+    }
+
+    pub fn wrapping_add(&self, other: Self) -> Self {
+        int3(
+            self.x.wrapping_add(other.x),
+            self.y.wrapping_add(other.y),
+            self.z.wrapping_add(other.z),
+        )
+    }
+}
+
+impl IntoIterator for Int3 {
+    type Item = i32;
+    type IntoIter = core::array::IntoIter<i32, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.to_array())
+    }
 }
 
 impl fmt::Display for Int3 {
@@ -92,7 +206,7 @@ impl fmt::Display for Int3 {
 
 impl AsRef<[i32]> for Int3 {
     fn as_ref(&self) -> &[i32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const i32, 3) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const i32, 3) }
     }
 }
 
@@ -214,3 +328,93 @@ impl RemAssign<i32> for Int3 {
         *self = self.rem(rhs);
     }
 }
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<i32>> for Int3 {
+    fn from(val: mint::Vector3<i32>) -> Self {
+        int3(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Int3> for mint::Vector3<i32> {
+    fn from(val: Int3) -> Self {
+        mint::Vector3 {
+            x: val.x,
+            y: val.y,
+            z: val.z,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::IVec3> for Int3 {
+    fn from(val: glam::IVec3) -> Self {
+        int3(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Int3> for glam::IVec3 {
+    fn from(val: Int3) -> Self {
+        glam::IVec3::new(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_vs_chebyshev() {
+        let a = int3(1, -2, 5);
+        let b = a + int3(3, 4, -2);
+        assert_eq!(a.manhattan_dist(b), 9);
+        assert_eq!(a.chebyshev_dist(b), 4);
+        assert_eq!(a.sqr_dist(b), 29);
+    }
+
+    #[test]
+    fn checked_and_wrapping_arithmetic() {
+        assert_eq!(Int3::new(0, 0, i32::MAX).checked_add(Int3::ONE), None);
+        assert_eq!(
+            int3(1, 2, 3).checked_mul(int3(2, 2, 2)),
+            Some(int3(2, 4, 6))
+        );
+        let max = Int3::new(i32::MAX, 0, i32::MIN);
+        assert_eq!(
+            max.saturating_add(int3(1, 1, -1)),
+            int3(i32::MAX, 1, i32::MIN)
+        );
+        assert_eq!(max.wrapping_add(int3(1, 0, 0)).x, i32::MIN);
+    }
+
+    #[test]
+    fn grid_neighbors() {
+        let c = int3(2, -1, 4);
+        let n6 = c.neighbors6();
+        assert!(n6.iter().all(|n| c.manhattan_dist(*n) == 1));
+        let n26 = c.neighbors26();
+        assert!(n26.iter().all(|n| c.chebyshev_dist(*n) == 1));
+        for (i, a) in n26.iter().enumerate() {
+            assert!(n26[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn array_round_trip_and_iteration() {
+        let p = Int3::new(1, 2, 3);
+        assert_eq!(p.to_array(), [1, 2, 3]);
+        assert_eq!(Int3::from_array(p.to_array()), p);
+        let sum: i32 = p.into_iter().sum();
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn lerp_rounds_to_nearest_cell() {
+        let (a, b) = (int3(0, 0, 0), int3(10, -10, 4));
+        assert_eq!(a.lerp(b, 0.5), int3(5, -5, 2));
+        assert_eq!(a.lerp(b, 0.04), int3(0, 0, 0));
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+}
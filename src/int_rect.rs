@@ -1,12 +1,15 @@
-use crate::{int2, Int2};
+use crate::{int2, Int2, Rect};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::hash::Hash;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct IntRect {
     pub x: i32,
@@ -15,7 +18,7 @@ pub struct IntRect {
     pub h: i32,
 }
 
-pub fn irect(x: i32, y: i32, w: i32, h: i32) -> IntRect {
+pub const fn irect(x: i32, y: i32, w: i32, h: i32) -> IntRect {
     IntRect { x, y, w, h }
 }
 
@@ -29,7 +32,7 @@ impl IntRect {
     };
 
     /// Create a new rectangle.
-    pub fn new(x: i32, y: i32, w: i32, h: i32) -> Self {
+    pub const fn new(x: i32, y: i32, w: i32, h: i32) -> Self {
         irect(x, y, w, h)
     }
 
@@ -43,6 +46,17 @@ impl IntRect {
         irect(0, 0, w, h)
     }
 
+    /// Create the smallest rectangle containing all the points, or an empty rectangle if
+    /// there are none.
+    pub fn from_points(points: &[Int2]) -> Self {
+        match points.split_first() {
+            Some((first, rest)) => rest.iter().fold(irect(first.x, first.y, 1, 1), |r, p| {
+                r.expanded_to_include(*p)
+            }),
+            None => Self::EMPTY,
+        }
+    }
+
     /// Return a version of the rectangle that is guaranteed not to have a negative size.
     pub fn absolute(&self) -> Self {
         let mut r = *self;
@@ -150,6 +164,26 @@ impl IntRect {
         int2(self.right(), self.center_y())
     }
 
+    /// The four corners, clockwise from the top-left.
+    pub fn corners(&self) -> [Int2; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// The four corners, counter-clockwise from the top-left.
+    pub fn corners_ccw(&self) -> [Int2; 4] {
+        [
+            self.top_left(),
+            self.bottom_left(),
+            self.bottom_right(),
+            self.top_right(),
+        ]
+    }
+
     pub fn area(&self) -> i32 {
         self.w * self.h
     }
@@ -162,6 +196,12 @@ impl IntRect {
         p.x >= self.x && p.y >= self.y && p.x < self.right() && p.y < self.bottom()
     }
 
+    /// Like [contains()](#method.contains), but points on the right and bottom edges are
+    /// inside too, treating the rectangle as a closed region.
+    pub fn contains_inclusive(&self, p: Int2) -> bool {
+        p.x >= self.x && p.y >= self.y && p.x <= self.right() && p.y <= self.bottom()
+    }
+
     pub fn contains_rect(&self, r: &Self) -> bool {
         r.x >= self.x && r.y >= self.y && r.right() <= self.right() && r.bottom() <= self.bottom()
     }
@@ -194,6 +234,45 @@ impl IntRect {
         irect((outer.w - w) / 2, (outer.h - h) / 2, w, h)
     }
 
+    /// Divide the rectangle by `factor`, rounding outwards so that scaling the result back
+    /// up still covers the original area. Panics if `factor` is not positive.
+    pub fn scaled_down(&self, factor: i32) -> Self {
+        assert!(factor > 0, "scale factor must be positive");
+        let r = self.non_neg();
+        let x = r.x.div_euclid(factor);
+        let y = r.y.div_euclid(factor);
+        let right = (r.right() + factor - 1).div_euclid(factor);
+        let bottom = (r.bottom() + factor - 1).div_euclid(factor);
+        irect(x, y, right - x, bottom - y)
+    }
+
+    /// Multiply the rectangle's position and size by `factor`.
+    pub fn scaled_up(&self, factor: i32) -> Self {
+        *self * factor
+    }
+
+    /// Convert to a floating-point rectangle.
+    pub fn to_rect(&self) -> Rect {
+        (*self).into()
+    }
+
+    /// Move the rectangle the least amount needed for it to fit inside `bounds`. If it is
+    /// larger than `bounds` on an axis, it is centered on that axis instead.
+    pub fn clamped_inside(&self, bounds: &Self) -> Self {
+        fn clamp(x: i32, w: i32, min: i32, size: i32) -> i32 {
+            match w > size {
+                true => min + (size - w) / 2,
+                false => x.clamp(min, min + size - w),
+            }
+        }
+        irect(
+            clamp(self.x, self.w, bounds.x, bounds.w),
+            clamp(self.y, self.h, bounds.y, bounds.h),
+            self.w,
+            self.h,
+        )
+    }
+
     pub fn conflate(&self, r: &Self) -> Self {
         let x = self.min_x().min(r.min_x());
         let y = self.min_y().min(r.min_y());
@@ -202,6 +281,20 @@ impl IntRect {
         irect(x, y, w - x, h - y)
     }
 
+    /// The smallest rectangle containing both this rectangle and the point. Like `contains`,
+    /// the point is treated as the cell to its bottom-right, so the right and bottom edges
+    /// extend one past it.
+    pub fn expanded_to_include(&self, p: Int2) -> Self {
+        let min = self.min().min(p);
+        let max = self.max().max(p + Int2::ONE);
+        irect(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    /// Grow the rectangle to contain the point.
+    pub fn expand_to_include(&mut self, p: Int2) {
+        *self = self.expanded_to_include(p);
+    }
+
     pub fn translate(&self, amount: Int2) -> Self {
         irect(self.x + amount.x, self.y + amount.y, self.w, self.h)
     }
@@ -223,15 +316,83 @@ impl IntRect {
         r
     }
 
+    /// Cut a strip `amount` wide off the left edge, returning the strip and the remainder.
+    /// The amount is clamped between zero and the rectangle's width.
+    pub fn cut_left(&self, amount: i32) -> (Self, Self) {
+        let a = amount.clamp(0, self.w.max(0));
+        (
+            irect(self.x, self.y, a, self.h),
+            irect(self.x + a, self.y, self.w - a, self.h),
+        )
+    }
+
+    /// Cut a strip `amount` wide off the right edge, returning the strip and the remainder.
+    /// The amount is clamped between zero and the rectangle's width.
+    pub fn cut_right(&self, amount: i32) -> (Self, Self) {
+        let a = amount.clamp(0, self.w.max(0));
+        (
+            irect(self.x + self.w - a, self.y, a, self.h),
+            irect(self.x, self.y, self.w - a, self.h),
+        )
+    }
+
+    /// Cut a strip `amount` tall off the top edge, returning the strip and the remainder.
+    /// The amount is clamped between zero and the rectangle's height.
+    pub fn cut_top(&self, amount: i32) -> (Self, Self) {
+        let a = amount.clamp(0, self.h.max(0));
+        (
+            irect(self.x, self.y, self.w, a),
+            irect(self.x, self.y + a, self.w, self.h - a),
+        )
+    }
+
+    /// Cut a strip `amount` tall off the bottom edge, returning the strip and the remainder.
+    /// The amount is clamped between zero and the rectangle's height.
+    pub fn cut_bottom(&self, amount: i32) -> (Self, Self) {
+        let a = amount.clamp(0, self.h.max(0));
+        (
+            irect(self.x, self.y + self.h - a, self.w, a),
+            irect(self.x, self.y, self.w, self.h - a),
+        )
+    }
+
+    /// The four edge strips of a frame `thickness` thick, clockwise starting from the top. The
+    /// top and bottom strips span the full width and the side strips fit between them, so
+    /// none of them overlap.
+    pub fn border(&self, thickness: i32) -> [Self; 4] {
+        let (top, rest) = self.cut_top(thickness);
+        let (bottom, rest) = rest.cut_bottom(thickness);
+        let (right, rest) = rest.cut_right(thickness);
+        let (left, _) = rest.cut_left(thickness);
+        [top, right, bottom, left]
+    }
+
     pub fn iter(&self) -> IntRectIter {
-        let pos = self.min();
+        let r = self.non_neg();
         IntRectIter {
-            min_x: pos.x,
-            max_x: self.max_x(),
-            max_y: self.max_y(),
-            pos,
+            min: r.min(),
+            w: r.w,
+            front: 0,
+            back: r.w as u64 * r.h as u64,
         }
     }
+
+    /// Iterate over the `chunk_w` by `chunk_h` tiles covering the rectangle, row by row. If the
+    /// size doesn't divide evenly, the tiles in the last row and column are clipped to fit.
+    pub fn chunks(&self, chunk_w: i32, chunk_h: i32) -> impl Iterator<Item = IntRect> {
+        let r = self.non_neg();
+        let (cols, rows) = match chunk_w > 0 && chunk_h > 0 {
+            true => ((r.w + chunk_w - 1) / chunk_w, (r.h + chunk_h - 1) / chunk_h),
+            false => (0, 0),
+        };
+        (0..rows).flat_map(move |row| {
+            (0..cols).map(move |col| {
+                let x = col * chunk_w;
+                let y = row * chunk_h;
+                irect(r.x + x, r.y + y, chunk_w.min(r.w - x), chunk_h.min(r.h - y))
+            })
+        })
+    }
 }
 
 impl IntoIterator for IntRect {
@@ -239,13 +400,7 @@ impl IntoIterator for IntRect {
     type IntoIter = IntRectIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        let pos = self.min();
-        IntRectIter {
-            min_x: pos.x,
-            max_x: self.max_x(),
-            max_y: self.max_y(),
-            pos,
-        }
+        self.iter()
     }
 }
 
@@ -281,26 +436,230 @@ impl SubAssign<Int2> for IntRect {
     }
 }
 
+impl Mul<i32> for IntRect {
+    type Output = Self;
+    fn mul(self, val: i32) -> IntRect {
+        irect(self.x * val, self.y * val, self.w * val, self.h * val)
+    }
+}
+
+impl MulAssign<i32> for IntRect {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = self.mul(rhs);
+    }
+}
+
+impl Div<i32> for IntRect {
+    type Output = Self;
+    fn div(self, val: i32) -> IntRect {
+        irect(self.x / val, self.y / val, self.w / val, self.h / val)
+    }
+}
+
+impl DivAssign<i32> for IntRect {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = self.div(rhs);
+    }
+}
+
+/// Iterates over every point in an `IntRect`, row by row from the top-left.
 pub struct IntRectIter {
-    min_x: i32,
-    max_x: i32,
-    max_y: i32,
-    pos: Int2,
+    min: Int2,
+    w: i32,
+    front: u64,
+    back: u64,
+}
+
+impl IntRectIter {
+    fn point(&self, i: u64) -> Int2 {
+        let w = self.w as u64;
+        int2(self.min.x + (i % w) as i32, self.min.y + (i / w) as i32)
+    }
 }
 
 impl Iterator for IntRectIter {
     type Item = Int2;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos.x < self.max_x && self.pos.y < self.max_y {
-            let p = self.pos;
-            self.pos.x += 1;
-            if self.pos.x >= self.max_x {
-                self.pos.x = self.min_x;
-                self.pos.y += 1;
-            }
+        if self.front < self.back {
+            let p = self.point(self.front);
+            self.front += 1;
             Some(p)
         } else {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IntRectIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.point(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for IntRectIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{int2, irect};
+
+    #[test]
+    fn iter_len_and_reverse() {
+        let r = irect(1, 2, 3, 2);
+        let mut it = r.iter();
+        assert_eq!(it.len(), r.area() as usize);
+        it.next();
+        it.next_back();
+        assert_eq!(it.len(), 4);
+        let points = [
+            int2(1, 2),
+            int2(2, 2),
+            int2(3, 2),
+            int2(1, 3),
+            int2(2, 3),
+            int2(3, 3),
+        ];
+        assert!(r.iter().eq(points.iter().copied()));
+        assert!(r.iter().rev().eq(points.iter().rev().copied()));
+        assert_eq!(irect(0, 0, 0, 5).iter().len(), 0);
+    }
+
+    #[test]
+    fn corners_winding() {
+        let r = irect(1, 2, 3, 4);
+        let [a, b, c, d] = r.corners();
+        assert_eq!(
+            [a, b, c, d],
+            [int2(1, 2), int2(4, 2), int2(4, 6), int2(1, 6)]
+        );
+        assert_eq!(r.corners_ccw(), [a, d, c, b]);
+    }
+
+    #[test]
+    fn clamped_inside_bounds() {
+        let bounds = irect(0, 0, 100, 50);
+        assert_eq!(
+            irect(90, 10, 20, 20).clamped_inside(&bounds),
+            irect(80, 10, 20, 20)
+        );
+        assert_eq!(
+            irect(-30, -5, 120, 10).clamped_inside(&bounds),
+            irect(-10, 0, 120, 10)
+        );
+    }
+
+    #[test]
+    fn chunks_clip_edges() {
+        let r = irect(5, 5, 10, 10);
+        let mut chunks = r.chunks(4, 4);
+        assert_eq!(r.chunks(4, 4).count(), 9);
+        assert_eq!(chunks.next(), Some(irect(5, 5, 4, 4)));
+        assert_eq!(chunks.nth(1), Some(irect(13, 5, 2, 4)));
+        assert_eq!(chunks.last(), Some(irect(13, 13, 2, 2)));
+        let area: i32 = r.chunks(4, 4).map(|c| c.area()).sum();
+        assert_eq!(area, r.area());
+        assert_eq!(r.chunks(0, 4).count(), 0);
+    }
+
+    #[test]
+    fn bounds_from_scattered_points() {
+        let points = [int2(3, 1), int2(-2, 4), int2(5, -1), int2(0, 2)];
+        let bounds = IntRect::from_points(&points);
+        assert_eq!(bounds, irect(-2, -1, 8, 6));
+        assert!(points.iter().all(|p| bounds.contains(*p)));
+        let mut r = irect(0, 0, 1, 1);
+        for p in &points {
+            r.expand_to_include(*p);
+        }
+        assert_eq!(r, bounds);
+        assert_eq!(IntRect::from_points(&[]), IntRect::EMPTY);
+    }
+
+    #[test]
+    fn scaled_down_covers_original() {
+        let r = irect(2, 2, 3, 3);
+        let down = r.scaled_down(2);
+        assert_eq!(down, irect(1, 1, 2, 2));
+        let up = down.scaled_up(2);
+        assert_eq!(up, irect(2, 2, 4, 4));
+        assert!(r.iter().all(|p| up.contains(p)));
+        assert_eq!(irect(-3, -1, 2, 2).scaled_down(2), irect(-2, -1, 2, 2));
+        assert_eq!(r * 2 / 2, r);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scaled_down_by_zero() {
+        irect(2, 2, 3, 3).scaled_down(0);
+    }
+
+    #[test]
+    fn cut_pieces_tile_the_original() {
+        let r = irect(2, 3, 10, 6);
+        let (strip, rest) = r.cut_left(4);
+        assert_eq!(strip, irect(2, 3, 4, 6));
+        assert_eq!(rest, irect(6, 3, 6, 6));
+        assert_eq!(strip.area() + rest.area(), r.area());
+        assert!(!strip.overlaps(&rest));
+        for p in r.iter() {
+            assert!(strip.contains(p) != rest.contains(p));
+        }
+
+        assert_eq!(r.cut_left(-3), (irect(2, 3, 0, 6), r));
+        assert_eq!(r.cut_left(50), (r, irect(12, 3, 0, 6)));
+        assert_eq!(r.cut_right(3), (irect(9, 3, 3, 6), irect(2, 3, 7, 6)));
+        assert_eq!(r.cut_top(2), (irect(2, 3, 10, 2), irect(2, 5, 10, 4)));
+        assert_eq!(r.cut_bottom(99), (r, irect(2, 3, 10, 0)));
+    }
+
+    #[test]
+    fn border_strips() {
+        let r = irect(0, 0, 10, 8);
+        let [top, right, bottom, left] = r.border(2);
+        assert_eq!(top, irect(0, 0, 10, 2));
+        assert_eq!(right, irect(8, 2, 2, 4));
+        assert_eq!(bottom, irect(0, 6, 10, 2));
+        assert_eq!(left, irect(0, 2, 2, 4));
+        let inner = irect(2, 2, 6, 4);
+        for p in r.iter() {
+            let n = [top, right, bottom, left, inner]
+                .iter()
+                .filter(|s| s.contains(p))
+                .count();
+            assert_eq!(n, 1);
+        }
+    }
+
+    #[test]
+    fn contains_inclusive_edges() {
+        let r = irect(1, 2, 3, 4);
+        for p in [int2(4, 3), int2(2, 6), int2(4, 6)] {
+            assert!(!r.contains(p));
+            assert!(r.contains_inclusive(p));
+        }
+        assert!(r.contains(int2(1, 2)));
+        assert!(!r.contains_inclusive(int2(5, 3)));
+        assert!(!r.contains_inclusive(int2(0, 3)));
+    }
+
+    #[test]
+    fn iter_len_beyond_i32() {
+        let r = irect(-10, 5, 50000, 50000);
+        let mut it = r.iter();
+        assert_eq!(it.len() as u64, 2_500_000_000);
+        assert_eq!(it.next(), Some(int2(-10, 5)));
+        assert_eq!(it.next_back(), Some(int2(49989, 50004)));
+        assert_eq!(it.next_back(), Some(int2(49988, 50004)));
+        assert_eq!(it.len() as u64, 2_499_999_997);
+    }
 }
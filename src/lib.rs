@@ -1,8 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[allow(clippy::many_single_char_names)]
+mod aabb;
+#[allow(clippy::many_single_char_names)]
+mod angles;
 #[allow(clippy::many_single_char_names)]
 mod approx;
 #[allow(clippy::many_single_char_names)]
+mod circle;
+#[allow(clippy::many_single_char_names, clippy::excessive_precision)]
 mod color;
 #[allow(clippy::many_single_char_names)]
+mod float;
+#[allow(clippy::many_single_char_names)]
 mod helper;
 #[allow(clippy::many_single_char_names)]
 mod int2;
@@ -15,23 +27,35 @@ mod mat3x2;
 #[allow(clippy::many_single_char_names)]
 mod mat4x4;
 #[allow(clippy::many_single_char_names)]
+mod plane;
+#[allow(clippy::many_single_char_names)]
 mod rect;
 #[allow(clippy::many_single_char_names)]
+mod segment;
+#[allow(clippy::many_single_char_names)]
+mod triangle;
+#[allow(clippy::many_single_char_names)]
 mod vec2;
 #[allow(clippy::many_single_char_names)]
 mod vec3;
 #[allow(clippy::many_single_char_names)]
 mod vec4;
 
-pub use crate::approx::{approx, approx_f32, Approx};
-pub use color::Color;
+pub use crate::approx::{approx, approx_f32, approx_slice, Approx};
+pub use aabb::{aabb, Aabb};
+pub use angles::{Degrees, Radians};
+pub use circle::{circle, Circle};
+pub use color::{BlendMode, Color, Gradient};
 pub use helper::*;
-pub use int2::{int2, Int2};
+pub use int2::{int2, Int2, Int2LineIter};
 pub use int3::{int3, Int3};
 pub use int_rect::{irect, IntRect, IntRectIter};
 pub use mat3x2::{mat3x2, Mat3x2};
 pub use mat4x4::{mat4x4, Mat4x4};
-pub use rect::{rect, Rect};
+pub use plane::{plane, Plane};
+pub use rect::{rect, Anchor, Rect};
+pub use segment::{segment2, Segment2};
+pub use triangle::{triangle2, triangle3, Triangle2, Triangle3};
 pub use vec2::{vec2, Vec2};
-pub use vec3::{vec3, Vec3};
+pub use vec3::{are_coplanar, rotate_around_axis, vec3, Vec3};
 pub use vec4::{vec4, Vec4};
@@ -1,18 +1,24 @@
-use crate::{vec2, Vec2};
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Mul, MulAssign, Index};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{rect, vec2, Mat4x4, Radians, Rect, Vec2};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, Mul, MulAssign};
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Mat3x2 {
     pub m: [f32; 6],
 }
 
-pub fn mat3x2(m: [f32; 6]) -> Mat3x2 {
+pub const fn mat3x2(m: [f32; 6]) -> Mat3x2 {
     Mat3x2 { m }
 }
 
@@ -24,7 +30,7 @@ impl Mat3x2 {
         m: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
     };
 
-    pub fn new(m: [f32; 6]) -> Self {
+    pub const fn new(m: [f32; 6]) -> Self {
         Self { m }
     }
 
@@ -43,7 +49,86 @@ impl Mat3x2 {
     }
 
     pub fn skew(amount: Vec2) -> Self {
-        mat3x2([0.0, amount.x.tan(), 0.0, amount.y.tan(), 1.0, 0.0])
+        mat3x2([1.0, amount.x.tan(), 0.0, amount.y.tan(), 1.0, 0.0])
+    }
+
+    /// Compose a scale, rotation and translation into one matrix. Points are
+    /// scaled first, then rotated, then translated, which is the same as
+    /// `Mat3x2::scale(scale).mult(&Mat3x2::rotation(rotation)).mult(&Mat3x2::translation(translation))`.
+    pub fn from_trs(translation: Vec2, rotation: impl Into<Radians>, scale: Vec2) -> Self {
+        let Radians(a) = rotation.into();
+        let c = a.cos();
+        let s = a.sin();
+        mat3x2([
+            c * scale.x,
+            -s * scale.y,
+            translation.x,
+            s * scale.x,
+            c * scale.y,
+            translation.y,
+        ])
+    }
+
+    /// Like [`Mat3x2::from_trs`], but the scale and rotation are applied around `origin`
+    /// instead of around zero. Translation is applied last.
+    pub fn from_scale_rotation_translation_around(
+        origin: Vec2,
+        scale: Vec2,
+        rotation: impl Into<Radians>,
+        translation: Vec2,
+    ) -> Self {
+        Self::translation(-origin).mult(&Self::from_trs(origin + translation, rotation, scale))
+    }
+
+    /// A rotation around `pivot` instead of around zero, so `pivot` itself stays in place.
+    pub fn rotation_around(pivot: Vec2, angle: impl Into<Radians>) -> Self {
+        let Radians(a) = angle.into();
+        let c = a.cos();
+        let s = a.sin();
+        mat3x2([
+            c,
+            -s,
+            pivot.x - c * pivot.x + s * pivot.y,
+            s,
+            c,
+            pivot.y - s * pivot.x - c * pivot.y,
+        ])
+    }
+
+    /// A scale around `pivot` instead of around zero, so `pivot` itself stays in place.
+    pub fn scale_around(pivot: Vec2, scale: Vec2) -> Self {
+        mat3x2([
+            scale.x,
+            0.0,
+            pivot.x - scale.x * pivot.x,
+            0.0,
+            scale.y,
+            pivot.y - scale.y * pivot.y,
+        ])
+    }
+
+    /// Lift the transform into 3D. See [Mat4x4::from_mat3x2()](struct.Mat4x4.html#method.from_mat3x2).
+    pub fn to_mat4x4(&self) -> Mat4x4 {
+        Mat4x4::from_mat3x2(self)
+    }
+
+    /// Check if two matrices are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
+    /// The absolute difference between each of the matrices' elements.
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        let mut m = self.m;
+        for (a, b) in m.iter_mut().zip(other.m.iter()) {
+            *a = (*a - b).abs();
+        }
+        mat3x2(m)
+    }
+
+    /// The largest absolute difference between any of the matrices' elements.
+    pub fn max_abs_diff(&self, other: &Self) -> f32 {
+        self.abs_diff(other).m.iter().fold(0.0, |a, b| a.max(*b))
     }
 
     pub fn transform(&self, p: Vec2) -> Vec2 {
@@ -67,17 +152,39 @@ impl Mat3x2 {
         )
     }
 
-    pub fn invert(&self) -> Self {
+    pub fn transform_rect(&self, r: Rect) -> Rect {
+        let a = self.transform(r.top_left());
+        let b = self.transform(r.top_right());
+        let c = self.transform(r.bottom_right());
+        let d = self.transform(r.bottom_left());
+        let min = a.min(b).min(c).min(d);
+        let max = a.max(b).max(c).max(d);
+        rect(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.m[0] * self.m[4] - self.m[3] * self.m[1]
+    }
+
+    pub fn try_invert(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
         let m = &self.m;
-        let invdet = 10.0 / (m[0] * m[4] - m[3] * m[1]);
-        mat3x2([
+        let invdet = 1.0 / det;
+        Some(mat3x2([
             m[4] * invdet,
             -m[1] * invdet,
             (m[1] * m[5] - m[2] * m[4]) * invdet,
             -m[3] * invdet,
             m[0] * invdet,
             -(m[0] * m[5] - m[2] * m[3]) * invdet,
-        ])
+        ]))
+    }
+
+    pub fn invert(&self) -> Self {
+        self.try_invert().unwrap_or(Self::ZERO)
     }
 
     pub fn mult(&self, other: &Self) -> Self {
@@ -142,6 +249,105 @@ impl Mul<Mat3x2> for Mat3x2 {
 
 impl MulAssign<Mat3x2> for Mat3x2 {
     fn mul_assign(&mut self, rhs: Mat3x2) {
-        *self = self.clone().mul(rhs);
+        *self = self.mul(rhs);
+    }
+}
+
+// The matrix is stored row by row, so each mint column is gathered from both rows.
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix2x3<f32>> for Mat3x2 {
+    fn from(val: mint::ColumnMatrix2x3<f32>) -> Self {
+        mat3x2([val.x.x, val.y.x, val.z.x, val.x.y, val.y.y, val.z.y])
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Mat3x2> for mint::ColumnMatrix2x3<f32> {
+    fn from(val: Mat3x2) -> Self {
+        let m = &val.m;
+        mint::ColumnMatrix2x3 {
+            x: mint::Vector2 { x: m[0], y: m[3] },
+            y: mint::Vector2 { x: m[1], y: m[4] },
+            z: mint::Vector2 { x: m[2], y: m[5] },
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vec2, Degrees};
+
+    #[test]
+    fn determinant_and_try_invert() {
+        assert!(crate::approx_f32(Mat3x2::rotation(0.7).determinant(), 1.0));
+        let scale = Mat3x2::scale(vec2(2.0, 3.0));
+        assert!(crate::approx_f32(scale.determinant(), 6.0));
+        let inv = scale.try_invert().unwrap();
+        assert!(inv.mult(&scale).approx_eq(&Mat3x2::IDENTITY));
+        assert!(Mat3x2::scale(vec2(0.0, 3.0)).try_invert().is_none());
+        assert_eq!(Mat3x2::scale(vec2(0.0, 3.0)).invert(), Mat3x2::ZERO);
+    }
+
+    #[test]
+    fn transform_rect_bounds() {
+        let square = rect(-0.5, -0.5, 1.0, 1.0);
+        let moved = Mat3x2::translation(vec2(2.0, 3.0)).transform_rect(square);
+        assert!(moved.approx_eq(&rect(1.5, 2.5, 1.0, 1.0)));
+        let rotated = Mat3x2::rotation(crate::PI / 4.0).transform_rect(square);
+        let half = crate::SQRT_2 / 2.0;
+        assert!(rotated.approx_eq(&rect(-half, -half, crate::SQRT_2, crate::SQRT_2)));
+    }
+
+    #[test]
+    fn from_trs_matches_manual_product() {
+        let (t, r, s) = (vec2(3.0, -2.0), 0.6, vec2(2.0, 0.5));
+        let manual = Mat3x2::scale(s)
+            .mult(&Mat3x2::rotation(r))
+            .mult(&Mat3x2::translation(t));
+        let composed = Mat3x2::from_trs(t, r, s);
+        assert!(composed.approx_eq(&manual));
+        let p = vec2(1.0, 4.0);
+        assert!(composed.transform(p).approx_eq(&manual.transform(p)));
+
+        let pivot = vec2(5.0, 5.0);
+        let around = Mat3x2::from_scale_rotation_translation_around(pivot, s, r, t);
+        assert!(around.transform(pivot).approx_eq(&(pivot + t)));
+    }
+
+    #[test]
+    fn skew_keeps_axes() {
+        assert_eq!(Mat3x2::skew(Vec2::ZERO), Mat3x2::IDENTITY);
+        let m = Mat3x2::skew(vec2(crate::PI / 4.0, 0.0));
+        assert_eq!(m.transform(Vec2::ZERO), Vec2::ZERO);
+        assert!(m.transform(vec2(3.0, 0.0)).approx_eq(&vec2(3.0, 0.0)));
+        assert!(m.transform(vec2(0.0, 2.0)).approx_eq(&vec2(2.0, 2.0)));
+        let m = Mat3x2::skew(vec2(0.0, 0.1));
+        assert!(m
+            .transform(vec2(1.0, 1.0))
+            .approx_eq(&vec2(1.0, 1.0 + 0.1f32.tan())));
+    }
+
+    #[test]
+    fn rotation_and_scale_around_pivot() {
+        let pivot = vec2(3.0, -2.0);
+        let close = |a: Vec2, b: Vec2| a.max_abs_diff(b) < 1e-5;
+
+        let m = Mat3x2::rotation_around(pivot, Radians(0.9));
+        assert!(close(m.transform(pivot), pivot));
+
+        let m = Mat3x2::rotation_around(pivot, Degrees(180.0));
+        let p = vec2(5.0, 1.0);
+        assert!(close(m.transform(p), pivot * 2.0 - p));
+
+        let by_hand = Mat3x2::translation(-pivot)
+            .mult(&Mat3x2::rotation(0.9))
+            .mult(&Mat3x2::translation(pivot));
+        let m = Mat3x2::rotation_around(pivot, Radians(0.9));
+        assert!(close(m.transform(p), by_hand.transform(p)));
+
+        let m = Mat3x2::scale_around(pivot, vec2(2.0, 0.5));
+        assert!(close(m.transform(pivot), pivot));
+        assert!(close(m.transform(p), vec2(7.0, -0.5)));
+    }
+}
@@ -1,18 +1,24 @@
-use crate::{vec2, vec3, vec4, Vec2, Vec3, Vec4};
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Mul, MulAssign, Index};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{vec2, vec3, vec4, Mat3x2, Plane, Vec2, Vec3, Vec4};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, Mul, MulAssign};
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Mat4x4 {
     pub m: [f32; 16],
 }
 
-pub fn mat4x4(m: [f32; 16]) -> Mat4x4 {
+pub const fn mat4x4(m: [f32; 16]) -> Mat4x4 {
     Mat4x4 { m }
 }
 
@@ -28,10 +34,18 @@ impl Mat4x4 {
         ],
     };
 
-    pub fn new(m: [f32; 16]) -> Self {
+    pub const fn new(m: [f32; 16]) -> Self {
         Self { m }
     }
 
+    /// Lift a 2D transform into 3D, transforming x and y the same way and leaving z as-is.
+    pub fn from_mat3x2(m: &Mat3x2) -> Self {
+        let m = &m.m;
+        mat4x4([
+            m[0], m[3], 0.0, 0.0, m[1], m[4], 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, m[2], m[5], 0.0, 1.0,
+        ])
+    }
+
     pub fn translation(amount: Vec3) -> Self {
         let (x, y, z) = amount.into();
         mat4x4([
@@ -70,6 +84,8 @@ impl Mat4x4 {
         ])
     }
 
+    /// Right-handed orthographic projection with depth in range (0.0 - 1.0).
+    /// Same as [orthographic_rh_zo()](#method.orthographic_rh_zo).
     pub fn orthographic(
         left: f32,
         right: f32,
@@ -77,6 +93,97 @@ impl Mat4x4 {
         top: f32,
         near_z: f32,
         far_z: f32,
+    ) -> Self {
+        Self::orthographic_rh_zo(left, right, bottom, top, near_z, far_z)
+    }
+
+    /// Right-handed orthographic projection, mapping depth from `near_z` to `far_z`
+    /// in range (0.0 - 1.0), as used by Direct3D, Metal, Vulkan and WebGPU.
+    pub fn orthographic_rh_zo(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_z: f32,
+        far_z: f32,
+    ) -> Self {
+        Self::orthographic_with(
+            left,
+            right,
+            bottom,
+            top,
+            1.0 / (near_z - far_z),
+            near_z / (near_z - far_z),
+        )
+    }
+
+    /// Right-handed orthographic projection, mapping depth from `near_z` to `far_z`
+    /// in range (-1.0 - 1.0), as used by OpenGL.
+    pub fn orthographic_rh_no(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_z: f32,
+        far_z: f32,
+    ) -> Self {
+        Self::orthographic_with(
+            left,
+            right,
+            bottom,
+            top,
+            2.0 / (near_z - far_z),
+            (near_z + far_z) / (near_z - far_z),
+        )
+    }
+
+    /// Left-handed orthographic projection, mapping depth from `near_z` to `far_z`
+    /// in range (0.0 - 1.0).
+    pub fn orthographic_lh_zo(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_z: f32,
+        far_z: f32,
+    ) -> Self {
+        Self::orthographic_with(
+            left,
+            right,
+            bottom,
+            top,
+            1.0 / (far_z - near_z),
+            near_z / (near_z - far_z),
+        )
+    }
+
+    /// Left-handed orthographic projection, mapping depth from `near_z` to `far_z`
+    /// in range (-1.0 - 1.0).
+    pub fn orthographic_lh_no(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_z: f32,
+        far_z: f32,
+    ) -> Self {
+        Self::orthographic_with(
+            left,
+            right,
+            bottom,
+            top,
+            2.0 / (far_z - near_z),
+            (near_z + far_z) / (near_z - far_z),
+        )
+    }
+
+    fn orthographic_with(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        z_scale: f32,
+        z_offset: f32,
     ) -> Self {
         mat4x4([
             2.0 / (right - left),
@@ -89,11 +196,11 @@ impl Mat4x4 {
             0.0,
             0.0,
             0.0,
-            1.0 / (near_z - far_z),
+            z_scale,
             0.0,
             (left + right) / (left - right),
             (top + bottom) / (bottom - top),
-            near_z / (near_z - far_z),
+            z_offset,
             1.0,
         ])
     }
@@ -152,50 +259,215 @@ impl Mat4x4 {
         ])
     }
 
-    pub fn perspective(width: f32, height: f32, near_z: f32, far_z: f32) -> Self {
+    /// Place an object at `position` with its z-axis turned towards `camera_pos`, using `up`
+    /// to keep it upright. If the camera is straight along `up` from the object, some other
+    /// axis perpendicular to the direction to the camera is used as up instead.
+    pub fn billboard(position: Vec3, camera_pos: Vec3, up: Vec3) -> Self {
+        let z = (camera_pos - position).norm();
+        let mut x = up.cross(z);
+        if x.sqr_len() <= f32::EPSILON {
+            let fallback = match z.z.abs() < 0.9 {
+                true => Vec3::FORWARD,
+                false => Vec3::RIGHT,
+            };
+            x = fallback.cross(z);
+        }
+        let x = x.norm();
+        let y = z.cross(x);
         mat4x4([
+            x.x, x.y, x.z, 0.0, y.x, y.y, y.z, 0.0, z.x, z.y, z.z, 0.0, position.x, position.y,
+            position.z, 1.0,
+        ])
+    }
+
+    /// Flatten geometry onto `plane` along the rays cast from `light`, for planar shadows. A
+    /// `light.w` of `1.0` is a point light at `light.xyz()`, and `0.0` is a directional light
+    /// shining along `-light.xyz()`. The result is projective, so transform points with
+    /// [transform4()](#method.transform4) and divide by `w`.
+    pub fn shadow(light: Vec4, plane: Plane) -> Self {
+        let p = vec4(plane.normal.x, plane.normal.y, plane.normal.z, plane.d);
+        let dot = p.dot(light);
+        let l = [light.x, light.y, light.z, light.w];
+        let p = [p.x, p.y, p.z, p.w];
+        let mut m = [0.0; 16];
+        for col in 0..4 {
+            for row in 0..4 {
+                let diag = match row == col {
+                    true => dot,
+                    false => 0.0,
+                };
+                m[col * 4 + row] = diag - l[row] * p[col];
+            }
+        }
+        mat4x4(m)
+    }
+
+    /// Right-handed perspective projection with depth in range (0.0 - 1.0), where `width`
+    /// and `height` are the size of the view at `near_z`.
+    pub fn perspective(width: f32, height: f32, near_z: f32, far_z: f32) -> Self {
+        Self::perspective_with(
             (2.0 * near_z) / width,
-            0.0,
-            0.0,
-            0.0,
             (2.0 * near_z) / height,
-            0.0,
-            0.0,
-            0.0,
             far_z / (near_z - far_z),
-            0.0,
-            0.0,
             -1.0,
-            0.0,
-            0.0,
-            0.0,
             (near_z * far_z) / (near_z - far_z),
-        ])
+        )
     }
 
+    /// Right-handed perspective projection with depth in range (0.0 - 1.0).
+    /// Same as [perspective_fov_rh_zo()](#method.perspective_fov_rh_zo).
     pub fn perspective_fov(fov: f32, aspect_ratio: f32, near_z: f32, far_z: f32) -> Self {
-        let num = 1.0 / (fov * 0.5).tan();
-        let num9 = num / aspect_ratio;
-        mat4x4([
-            num9,
-            0.0,
-            0.0,
-            0.0,
-            num,
-            0.0,
-            0.0,
-            0.0,
-            0.0,
-            0.0,
+        Self::perspective_fov_rh_zo(fov, aspect_ratio, near_z, far_z)
+    }
+
+    /// Right-handed perspective projection, mapping depth from `near_z` to `far_z`
+    /// in range (0.0 - 1.0), as used by Direct3D, Metal, Vulkan and WebGPU.
+    ///
+    /// For a reversed-Z depth buffer, swap `near_z` and `far_z`.
+    pub fn perspective_fov_rh_zo(fov: f32, aspect_ratio: f32, near_z: f32, far_z: f32) -> Self {
+        let y = 1.0 / (fov * 0.5).tan();
+        Self::perspective_with(
+            y / aspect_ratio,
+            y,
             far_z / (near_z - far_z),
             -1.0,
-            0.0,
-            0.0,
-            0.0,
             (near_z * far_z) / (near_z - far_z),
+        )
+    }
+
+    /// Right-handed perspective projection, mapping depth from `near_z` to `far_z`
+    /// in range (-1.0 - 1.0), as used by OpenGL.
+    pub fn perspective_fov_rh_no(fov: f32, aspect_ratio: f32, near_z: f32, far_z: f32) -> Self {
+        let y = 1.0 / (fov * 0.5).tan();
+        Self::perspective_with(
+            y / aspect_ratio,
+            y,
+            (near_z + far_z) / (near_z - far_z),
+            -1.0,
+            (2.0 * near_z * far_z) / (near_z - far_z),
+        )
+    }
+
+    /// Left-handed perspective projection, mapping depth from `near_z` to `far_z`
+    /// in range (0.0 - 1.0).
+    ///
+    /// For a reversed-Z depth buffer, swap `near_z` and `far_z`.
+    pub fn perspective_fov_lh_zo(fov: f32, aspect_ratio: f32, near_z: f32, far_z: f32) -> Self {
+        let y = 1.0 / (fov * 0.5).tan();
+        Self::perspective_with(
+            y / aspect_ratio,
+            y,
+            far_z / (far_z - near_z),
+            1.0,
+            (near_z * far_z) / (near_z - far_z),
+        )
+    }
+
+    /// Left-handed perspective projection, mapping depth from `near_z` to `far_z`
+    /// in range (-1.0 - 1.0).
+    pub fn perspective_fov_lh_no(fov: f32, aspect_ratio: f32, near_z: f32, far_z: f32) -> Self {
+        let y = 1.0 / (fov * 0.5).tan();
+        Self::perspective_with(
+            y / aspect_ratio,
+            y,
+            (near_z + far_z) / (far_z - near_z),
+            1.0,
+            (2.0 * near_z * far_z) / (near_z - far_z),
+        )
+    }
+
+    fn perspective_with(x: f32, y: f32, z_scale: f32, w_sign: f32, z_offset: f32) -> Self {
+        mat4x4([
+            x, 0.0, 0.0, 0.0, 0.0, y, 0.0, 0.0, 0.0, 0.0, z_scale, w_sign, 0.0, 0.0, z_offset, 0.0,
         ])
     }
 
+    /// Check if two matrices are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
+    /// The absolute difference between each of the matrices' elements.
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        let mut m = self.m;
+        for (a, b) in m.iter_mut().zip(other.m.iter()) {
+            *a = (*a - b).abs();
+        }
+        mat4x4(m)
+    }
+
+    /// The largest absolute difference between any of the matrices' elements.
+    pub fn max_abs_diff(&self, other: &Self) -> f32 {
+        self.abs_diff(other).m.iter().fold(0.0, |a, b| a.max(*b))
+    }
+
+    /// Get the element at `row` and `col`. The matrix is stored column-major, so this
+    /// reads `m[col * 4 + row]`, and the translation is in column 3. Both `row` and `col`
+    /// must be less than 4.
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        debug_assert!(row < 4 && col < 4);
+        self.m[col * 4 + row]
+    }
+
+    /// Set the element at `row` and `col`, which is stored at `m[col * 4 + row]`. Both `row`
+    /// and `col` must be less than 4.
+    pub fn set(&mut self, row: usize, col: usize, value: f32) {
+        debug_assert!(row < 4 && col < 4);
+        self.m[col * 4 + row] = value;
+    }
+
+    /// The translation of the matrix, stored in its last column.
+    pub fn translation_part(&self) -> Vec3 {
+        vec3(self.m[12], self.m[13], self.m[14])
+    }
+
+    /// The scale of the matrix, which is the length of each of its basis columns. These are
+    /// never negative, so any reflection in the matrix is dropped.
+    pub fn scale_part(&self) -> Vec3 {
+        vec3(
+            vec3(self.m[0], self.m[1], self.m[2]).len(),
+            vec3(self.m[4], self.m[5], self.m[6]).len(),
+            vec3(self.m[8], self.m[9], self.m[10]).len(),
+        )
+    }
+
+    /// The normalized x-axis of the matrix's basis.
+    pub fn right(&self) -> Vec3 {
+        vec3(self.m[0], self.m[1], self.m[2]).norm()
+    }
+
+    /// The normalized y-axis of the matrix's basis.
+    pub fn up(&self) -> Vec3 {
+        vec3(self.m[4], self.m[5], self.m[6]).norm()
+    }
+
+    /// The normalized z-axis of the matrix's basis, which points towards `Vec3::FORWARD`.
+    pub fn forward(&self) -> Vec3 {
+        vec3(self.m[8], self.m[9], self.m[10]).norm()
+    }
+
+    /// Extract the six clip planes of a view-projection matrix, with their normals facing
+    /// inwards, in the order: left, right, bottom, top, near, far.
+    ///
+    /// This assumes depth in range (0.0 - 1.0), as produced by the `_zo` projections.
+    pub fn frustum_planes(&self) -> [Plane; 6] {
+        let m = &self.m;
+        let row = |i: usize| vec4(m[i], m[4 + i], m[8 + i], m[12 + i]);
+        let plane = |v: Vec4| {
+            let len = v.truncate().len();
+            crate::plane(v.truncate() / len, v.w / len)
+        };
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        [
+            plane(w + x),
+            plane(w - x),
+            plane(w + y),
+            plane(w - y),
+            plane(z),
+            plane(w - z),
+        ]
+    }
+
     pub fn transform4(&self, p: &Vec4) -> Vec4 {
         let m = &self.m;
         vec4(
@@ -302,6 +574,93 @@ impl Mat4x4 {
         ])
     }
 
+    /// Linearly interpolate each of the matrix elements. This is only suitable for matrices
+    /// that are already similar, since blending rotations this way skews and shrinks them.
+    /// See [lerp_trs()](#method.lerp_trs).
+    pub fn lerp_raw(&self, other: &Self, t: f32) -> Self {
+        let mut m = self.m;
+        for (a, b) in m.iter_mut().zip(other.m.iter()) {
+            *a = crate::lerp(*a, *b, t);
+        }
+        mat4x4(m)
+    }
+
+    /// Interpolate between two transforms by splitting them into translation, rotation and
+    /// scale, lerping the translation and scale and slerping the rotation. Both matrices are
+    /// expected to be made from those three parts, without shearing or negative scale.
+    pub fn lerp_trs(&self, other: &Self, t: f32) -> Self {
+        let s = self.scale_part().lerp(other.scale_part(), t);
+        let p = self.translation_part().lerp(other.translation_part(), t);
+        let (x, y, z, w) = quat_slerp(self.rotation_quat(), other.rotation_quat(), t).into();
+        mat4x4([
+            (1.0 - 2.0 * (y * y + z * z)) * s.x,
+            (2.0 * (x * y + w * z)) * s.x,
+            (2.0 * (x * z - w * y)) * s.x,
+            0.0,
+            (2.0 * (x * y - w * z)) * s.y,
+            (1.0 - 2.0 * (x * x + z * z)) * s.y,
+            (2.0 * (y * z + w * x)) * s.y,
+            0.0,
+            (2.0 * (x * z + w * y)) * s.z,
+            (2.0 * (y * z - w * x)) * s.z,
+            (1.0 - 2.0 * (x * x + y * y)) * s.z,
+            0.0,
+            p.x,
+            p.y,
+            p.z,
+            1.0,
+        ])
+    }
+
+    /// The rotation of the matrix as an `(x, y, z, w)` quaternion, with the scale removed.
+    fn rotation_quat(&self) -> Vec4 {
+        let (r0, r1, r2) = (self.right(), self.up(), self.forward());
+        let trace = r0.x + r1.y + r2.z;
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            vec4(
+                (r1.z - r2.y) / s,
+                (r2.x - r0.z) / s,
+                (r0.y - r1.x) / s,
+                0.25 * s,
+            )
+        } else if r0.x > r1.y && r0.x > r2.z {
+            let s = (1.0 + r0.x - r1.y - r2.z).sqrt() * 2.0;
+            vec4(
+                0.25 * s,
+                (r1.x + r0.y) / s,
+                (r2.x + r0.z) / s,
+                (r1.z - r2.y) / s,
+            )
+        } else if r1.y > r2.z {
+            let s = (1.0 + r1.y - r0.x - r2.z).sqrt() * 2.0;
+            vec4(
+                (r1.x + r0.y) / s,
+                0.25 * s,
+                (r2.y + r1.z) / s,
+                (r2.x - r0.z) / s,
+            )
+        } else {
+            let s = (1.0 + r2.z - r0.x - r1.y).sqrt() * 2.0;
+            vec4(
+                (r2.x + r0.z) / s,
+                (r2.y + r1.z) / s,
+                0.25 * s,
+                (r0.y - r1.x) / s,
+            )
+        }
+    }
+
+    /// Compose two transforms, so that transforming a point by the result is the same as
+    /// transforming it by `self` and then by `other`. Same as `self.mult(other)` and
+    /// `self * other`.
+    pub fn then(&self, other: &Self) -> Self {
+        self.mult(other)
+    }
+
+    /// Multiply two matrices. Because the matrices are stored column-major, the result
+    /// applies `self` first and `other` second, meaning `a.mult(&b)` is the matrix product
+    /// `B × A` in column-vector notation. See [then()](#method.then).
     pub fn mult(&self, other: &Self) -> Self {
         let a = &self.m;
         let b = &other.m;
@@ -326,12 +685,34 @@ impl Mat4x4 {
     }
 }
 
+/// Spherical interpolation between two unit quaternions, taking the shortest path.
+fn quat_slerp(a: Vec4, b: Vec4, t: f32) -> Vec4 {
+    let mut b = b;
+    let mut dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+    if dot < 0.0 {
+        b = -b;
+        dot = -dot;
+    }
+    if dot > 0.9995 {
+        return a.lerp(b, t).norm();
+    }
+    let theta = dot.min(1.0).acos();
+    let sin = theta.sin();
+    a * (((1.0 - t) * theta).sin() / sin) + b * ((t * theta).sin() / sin)
+}
+
 impl AsRef<[f32]> for Mat4x4 {
     fn as_ref(&self) -> &[f32] {
         &self.m
     }
 }
 
+impl AsMut<[f32]> for Mat4x4 {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.m
+    }
+}
+
 impl Index<usize> for Mat4x4 {
     type Output = f32;
     fn index(&self, index: usize) -> &Self::Output {
@@ -388,6 +769,310 @@ impl Mul<Mat4x4> for Mat4x4 {
 
 impl MulAssign<Mat4x4> for Mat4x4 {
     fn mul_assign(&mut self, rhs: Mat4x4) {
-        *self = self.clone().mul(rhs);
+        *self = self.mul(rhs);
+    }
+}
+
+// Both sides store their elements column by column, so the flat arrays line up exactly.
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f32>> for Mat4x4 {
+    fn from(val: mint::ColumnMatrix4<f32>) -> Self {
+        mat4x4(val.into())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Mat4x4> for mint::ColumnMatrix4<f32> {
+    fn from(val: Mat4x4) -> Self {
+        val.m.into()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Mat4> for Mat4x4 {
+    fn from(val: glam::Mat4) -> Self {
+        mat4x4(val.to_cols_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Mat4x4> for glam::Mat4 {
+    fn from(val: Mat4x4) -> Self {
+        glam::Mat4::from_cols_array(&val.m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vec2, vec3, vec4, Mat3x2, Plane, PI};
+
+    #[test]
+    fn translation_is_column_major() {
+        let m = Mat4x4::translation(vec3(1.0, 2.0, 3.0));
+        assert_eq!(&m.m[12..16], &[1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_round_trip() {
+        let m = Mat4x4::rotation_y(0.5).mult(&Mat4x4::translation(vec3(1.0, 2.0, 3.0)));
+        let c: mint::ColumnMatrix4<f32> = m.into();
+        assert_eq!(c.w.x, 1.0);
+        assert_eq!(c.w.y, 2.0);
+        assert_eq!(c.w.z, 3.0);
+        let back: Mat4x4 = c.into();
+        assert_eq!(back.m, m.m);
+    }
+
+    #[test]
+    fn projection_depth_ranges() {
+        let depth = |m: Mat4x4, z: f32| {
+            let p = m.transform4(&vec4(0.0, 0.0, z, 1.0));
+            p.z / p.w
+        };
+        let (near, far) = (0.5, 50.0);
+        let cases = [
+            (
+                Mat4x4::perspective_fov_rh_zo(1.2, 1.5, near, far),
+                -1.0,
+                0.0,
+            ),
+            (
+                Mat4x4::perspective_fov_rh_no(1.2, 1.5, near, far),
+                -1.0,
+                -1.0,
+            ),
+            (Mat4x4::perspective_fov_lh_zo(1.2, 1.5, near, far), 1.0, 0.0),
+            (
+                Mat4x4::perspective_fov_lh_no(1.2, 1.5, near, far),
+                1.0,
+                -1.0,
+            ),
+            (
+                Mat4x4::orthographic_rh_zo(-1.0, 1.0, -1.0, 1.0, near, far),
+                -1.0,
+                0.0,
+            ),
+            (
+                Mat4x4::orthographic_rh_no(-1.0, 1.0, -1.0, 1.0, near, far),
+                -1.0,
+                -1.0,
+            ),
+            (
+                Mat4x4::orthographic_lh_zo(-1.0, 1.0, -1.0, 1.0, near, far),
+                1.0,
+                0.0,
+            ),
+            (
+                Mat4x4::orthographic_lh_no(-1.0, 1.0, -1.0, 1.0, near, far),
+                1.0,
+                -1.0,
+            ),
+        ];
+        for &(m, forward, near_depth) in &cases {
+            assert!((depth(m, forward * near) - near_depth).abs() < 1e-4);
+            assert!((depth(m, forward * far) - 1.0).abs() < 1e-4);
+        }
+        let m = Mat4x4::perspective_fov(1.2, 1.5, near, far);
+        assert!(m.approx_eq(&Mat4x4::perspective_fov_rh_zo(1.2, 1.5, near, far)));
+    }
+
+    #[test]
+    fn trs_accessors() {
+        let m = Mat4x4::scale(vec3(2.0, 3.0, 4.0))
+            .mult(&Mat4x4::rotation_y(PI / 2.0))
+            .mult(&Mat4x4::translation(vec3(1.0, 2.0, 3.0)));
+        assert!(m.translation_part().approx_eq(&vec3(1.0, 2.0, 3.0)));
+        assert!(m.scale_part().approx_eq(&vec3(2.0, 3.0, 4.0)));
+        assert!(m.right().approx_eq(&Vec3::BACK));
+        assert!(m.up().approx_eq(&Vec3::UP));
+        assert!(m.forward().approx_eq(&Vec3::RIGHT));
+    }
+
+    #[test]
+    fn frustum_planes_of_orthographic_box() {
+        let m = Mat4x4::orthographic_rh_zo(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+        let planes = m.frustum_planes();
+        let inside = vec3(1.5, -0.5, -5.0);
+        assert!(planes.iter().all(|p| p.distance_to(inside) > 0.0));
+        for p in &planes {
+            assert!(crate::approx_f32(p.normal.len(), 1.0));
+        }
+        let [left, right, bottom, top, near, far] = planes;
+        assert!(crate::approx_f32(left.distance_to(inside), 3.5));
+        assert!(crate::approx_f32(right.distance_to(inside), 0.5));
+        assert!(crate::approx_f32(bottom.distance_to(inside), 0.5));
+        assert!(crate::approx_f32(top.distance_to(inside), 1.5));
+        assert!(crate::approx_f32(near.distance_to(inside), 4.0));
+        assert!(crate::approx_f32(far.distance_to(inside), 5.0));
+        assert!(near.distance_to(vec3(0.0, 0.0, 0.0)) < 0.0);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_round_trip() {
+        let m = Mat4x4::rotation_y(0.5).mult(&Mat4x4::translation(vec3(1.0, 2.0, 3.0)));
+        let g: glam::Mat4 = m.into();
+        assert_eq!(g.w_axis, glam::Vec4::new(1.0, 2.0, 3.0, 1.0));
+        let back: Mat4x4 = g.into();
+        assert_eq!(back.m.map(f32::to_bits), m.m.map(f32::to_bits));
+
+        let v = vec4(0.1, -0.0, 3.5, f32::MIN_POSITIVE);
+        let back: Vec4 = glam::Vec4::from(v).into();
+        assert_eq!(back.x.to_bits(), v.x.to_bits());
+        assert_eq!(back.y.to_bits(), v.y.to_bits());
+        assert_eq!(back.w.to_bits(), v.w.to_bits());
+        let v3 = vec3(0.1, -0.0, 3.5);
+        let back: Vec3 = glam::Vec3::from(v3).into();
+        assert_eq!(back.y.to_bits(), v3.y.to_bits());
+        let v2 = crate::vec2(-0.0, 0.1);
+        assert_eq!(
+            crate::Vec2::from(glam::Vec2::from(v2)).x.to_bits(),
+            v2.x.to_bits()
+        );
+        let p = crate::int3(-4, 5, i32::MAX);
+        assert_eq!(crate::Int3::from(glam::IVec3::from(p)), p);
+        let q = crate::int2(i32::MIN, 7);
+        assert_eq!(crate::Int2::from(glam::IVec2::from(q)), q);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn get_set_row_col() {
+        let mut m = Mat4x4::IDENTITY;
+        m.set(0, 3, 5.0);
+        assert_eq!(m.get(0, 3), 5.0);
+        assert_eq!(m.m[12], 5.0);
+        assert_eq!(m.translation_part(), vec3(5.0, 0.0, 0.0));
+        for x in m.as_mut().iter_mut() {
+            *x *= 2.0;
+        }
+        assert_eq!(m.get(3, 3), 2.0);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn get_out_of_range() {
+        // Without the check this would silently read `m[4]`, which is `(0, 1)`
+        Mat4x4::IDENTITY.get(4, 0);
+    }
+
+    #[test]
+    fn then_composition_order() {
+        let t = Mat4x4::translation(vec3(1.0, 0.0, 0.0));
+        let r = Mat4x4::rotation_z(PI / 2.0);
+        let p = vec3(1.0, 0.0, 0.0);
+        assert!(r.transform3(&p).approx_eq(&vec3(0.0, 1.0, 0.0)));
+        // Translate to (2, 0, 0), then rotate a quarter turn around z
+        assert!(t.then(&r).transform3(&p).approx_eq(&vec3(0.0, 2.0, 0.0)));
+        // Rotate to (0, 1, 0), then translate along x
+        assert!(r.then(&t).transform3(&p).approx_eq(&vec3(1.0, 1.0, 0.0)));
+        assert_eq!(t.then(&r), t * r);
+        assert_eq!(t.then(&r), t.mult(&r));
+    }
+
+    #[test]
+    fn lerp_trs_endpoints_and_midpoint() {
+        let a = Mat4x4::scale(vec3(1.0, 2.0, 1.0))
+            .mult(&Mat4x4::rotation_x(0.3))
+            .mult(&Mat4x4::translation(vec3(1.0, 2.0, 3.0)));
+        let b = Mat4x4::scale(vec3(2.0, 2.0, 3.0))
+            .mult(&Mat4x4::rotation_y(1.2))
+            .mult(&Mat4x4::translation(vec3(-4.0, 0.0, 5.0)));
+        assert!(a.lerp_trs(&b, 0.0).max_abs_diff(&a) < 1e-5);
+        assert!(a.lerp_trs(&b, 1.0).max_abs_diff(&b) < 1e-5);
+
+        let from = Mat4x4::rotation_y(0.0);
+        let to = Mat4x4::rotation_y(PI / 2.0);
+        let mid = from.lerp_trs(&to, 0.5);
+        assert!(mid.max_abs_diff(&Mat4x4::rotation_y(PI / 4.0)) < 1e-5);
+        let det = |m: &Mat4x4| {
+            let x = vec3(m.m[0], m.m[1], m.m[2]);
+            let y = vec3(m.m[4], m.m[5], m.m[6]);
+            let z = vec3(m.m[8], m.m[9], m.m[10]);
+            x.cross(y).dot(z)
+        };
+        assert!((det(&mid) - 1.0).abs() < 1e-5);
+        assert!(det(&from.lerp_raw(&to, 0.5)) < 0.9);
+    }
+
+    #[test]
+    fn from_mat3x2_matches_2d_transform() {
+        let m2 = Mat3x2::rotation(0.7)
+            .mult(&Mat3x2::scale(vec2(2.0, -0.5)))
+            .mult(&Mat3x2::translation(vec2(3.0, -4.0)));
+        let m4 = Mat4x4::from_mat3x2(&m2);
+        assert_eq!(m2.to_mat4x4(), m4);
+        for p in [vec2(0.0, 0.0), vec2(1.0, 2.0), vec2(-3.5, 0.25)] {
+            let a = m2.transform(p);
+            let b = m4.transform4(&vec4(p.x, p.y, 0.0, 1.0));
+            assert!((a.x - b.x).abs() < 1e-5 && (a.y - b.y).abs() < 1e-5);
+            assert_eq!((b.z, b.w), (0.0, 1.0));
+        }
+        assert_eq!(Mat4x4::from_mat3x2(&Mat3x2::IDENTITY), Mat4x4::IDENTITY);
+    }
+
+    #[test]
+    fn max_abs_diff_over_all_elements() {
+        let a = Mat4x4::IDENTITY;
+        let mut b = a;
+        b.m[7] = -0.25;
+        b.m[14] = 2.0;
+        assert_eq!(a.max_abs_diff(&b), 2.0);
+        assert_eq!(a.abs_diff(&b).m[7], 0.25);
+        assert_eq!(a.max_abs_diff(&a), 0.0);
+    }
+
+    #[test]
+    fn billboard_faces_camera() {
+        let pos = vec3(1.0, 2.0, 3.0);
+        let cam = vec3(-4.0, 6.0, 10.0);
+        let m = Mat4x4::billboard(pos, cam, Vec3::UP);
+        let forward = m.transform3_dir(&Vec3::FORWARD);
+        assert!(forward.max_abs_diff((cam - pos).norm()) < 1e-5);
+        assert!(m.transform3(&Vec3::ZERO).max_abs_diff(pos) < 1e-5);
+        assert!(m.transform3_dir(&Vec3::RIGHT).dot(Vec3::UP).abs() < 1e-5);
+    }
+
+    #[test]
+    fn billboard_parallel_to_up_falls_back() {
+        let pos = vec3(1.0, 2.0, 3.0);
+        for (cam, up) in [
+            (pos + Vec3::UP * 5.0, Vec3::UP),
+            (pos - Vec3::UP * 5.0, Vec3::UP),
+            (pos + Vec3::FORWARD * 5.0, Vec3::FORWARD),
+            (pos - Vec3::FORWARD * 5.0, Vec3::FORWARD),
+        ] {
+            let m = Mat4x4::billboard(pos, cam, up);
+            assert!(m.m.iter().all(|v| v.is_finite()));
+            let forward = m.transform3_dir(&Vec3::FORWARD);
+            assert!(forward.max_abs_diff((cam - pos).norm()) < 1e-5);
+            let x = m.transform3_dir(&Vec3::RIGHT);
+            assert!((x.len() - 1.0).abs() < 1e-5);
+            assert!(x.dot(forward).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn shadow_projects_onto_plane() {
+        let project = |m: &Mat4x4, p: Vec3| {
+            let h = m.transform4(&vec4(p.x, p.y, p.z, 1.0));
+            vec3(h.x / h.w, h.y / h.w, h.z / h.w)
+        };
+        let ground = Plane::from_point_normal(Vec3::ZERO, Vec3::UP);
+        let p = vec3(2.0, 5.0, 1.0);
+
+        let point = Mat4x4::shadow(vec4(0.0, 10.0, 0.0, 1.0), ground);
+        let s = project(&point, p);
+        assert!(ground.distance_to(s).abs() < 1e-5);
+        assert!(s.max_abs_diff(vec3(4.0, 0.0, 2.0)) < 1e-5);
+
+        let sun = Mat4x4::shadow(vec4(0.0, 1.0, 0.0, 0.0), ground);
+        assert!(project(&sun, p).max_abs_diff(vec3(2.0, 0.0, 1.0)) < 1e-5);
+
+        let tilted = Plane::from_point_normal(vec3(0.0, -1.0, 0.0), vec3(1.0, 2.0, -0.5).norm());
+        let m = Mat4x4::shadow(vec4(3.0, 8.0, -2.0, 1.0), tilted);
+        assert!(tilted.distance_to(project(&m, p)).abs() < 1e-4);
+    }
+}
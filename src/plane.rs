@@ -0,0 +1,97 @@
+use crate::Vec3;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A plane in 3D space, made up of all points `p` where `normal.dot(p) + d == 0`.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+/// Easy constructor.
+pub const fn plane(normal: Vec3, d: f32) -> Plane {
+    Plane { normal, d }
+}
+
+impl Plane {
+    /// Create a new plane.
+    pub const fn new(normal: Vec3, d: f32) -> Self {
+        plane(normal, d)
+    }
+
+    /// Create a plane passing through the point `p`, facing in the direction `n`.
+    pub fn from_point_normal(p: Vec3, n: Vec3) -> Self {
+        let normal = n.norm();
+        plane(normal, -normal.dot(p))
+    }
+
+    /// Create a plane passing through three points. Looking at the plane from the side its
+    /// normal faces, the points wind counter-clockwise.
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    /// The signed distance from the plane to the point, positive on the side the normal faces.
+    pub fn distance_to(&self, p: Vec3) -> f32 {
+        self.normal.dot(p) + self.d
+    }
+
+    /// Cast a ray against the plane, returning how far along `dir` it hits. Rays running
+    /// parallel to the plane or pointing away from it miss.
+    pub fn ray_intersect(&self, origin: Vec3, dir: Vec3) -> Option<f32> {
+        let denom = self.normal.dot(dir);
+        if denom.abs() < f32::EPSILON {
+            return None;
+        }
+        let t = -self.distance_to(origin) / denom;
+        match t >= 0.0 {
+            true => Some(t),
+            false => None,
+        }
+    }
+}
+
+impl AsRef<[f32]> for Plane {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 4) }
+    }
+}
+
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.normal, self.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec3;
+
+    #[test]
+    fn xz_plane_distance_and_ray() {
+        let p = Plane::from_points(Vec3::ZERO, vec3(0.0, 0.0, 1.0), vec3(1.0, 0.0, 0.0));
+        assert!(p.normal.approx_eq(&Vec3::UP));
+        assert!(p.distance_to(vec3(3.0, 2.0, -1.0)) > 0.0);
+        assert!(p.distance_to(vec3(3.0, -2.0, -1.0)) < 0.0);
+        let lifted = Plane::from_point_normal(vec3(0.0, 1.0, 0.0), vec3(0.0, 5.0, 0.0));
+        assert!(crate::approx_f32(
+            lifted.distance_to(vec3(4.0, 3.0, 2.0)),
+            2.0
+        ));
+
+        let t = p
+            .ray_intersect(vec3(1.0, 4.0, 2.0), vec3(0.0, -2.0, 0.0))
+            .unwrap();
+        assert!(crate::approx_f32(t, 2.0));
+        assert_eq!(p.ray_intersect(vec3(1.0, 4.0, 2.0), Vec3::UP), None);
+        assert_eq!(p.ray_intersect(vec3(1.0, 4.0, 2.0), Vec3::RIGHT), None);
+    }
+}
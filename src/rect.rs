@@ -1,12 +1,19 @@
-use crate::{vec2, IntRect, Vec2};
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Sub, AddAssign, SubAssign, MulAssign, DivAssign};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{irect, vec2, IntRect, Vec2};
+use alloc::vec::Vec;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 #[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Rect {
     pub x: f32,
@@ -15,10 +22,43 @@ pub struct Rect {
     pub h: f32,
 }
 
-pub fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+pub const fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
     Rect { x, y, w, h }
 }
 
+/// A point on a rectangle's edges or center that another rectangle can be aligned to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Anchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    /// The anchor's position within a rectangle, from (0.0, 0.0) at the top-left to
+    /// (1.0, 1.0) at the bottom-right.
+    pub fn factor(self) -> Vec2 {
+        match self {
+            Anchor::TopLeft => vec2(0.0, 0.0),
+            Anchor::TopCenter => vec2(0.5, 0.0),
+            Anchor::TopRight => vec2(1.0, 0.0),
+            Anchor::CenterLeft => vec2(0.0, 0.5),
+            Anchor::Center => vec2(0.5, 0.5),
+            Anchor::CenterRight => vec2(1.0, 0.5),
+            Anchor::BottomLeft => vec2(0.0, 1.0),
+            Anchor::BottomCenter => vec2(0.5, 1.0),
+            Anchor::BottomRight => vec2(1.0, 1.0),
+        }
+    }
+}
+
 impl Rect {
     pub const EMPTY: Self = Self {
         x: 0.0,
@@ -27,7 +67,7 @@ impl Rect {
         h: 0.0,
     };
 
-    pub fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+    pub const fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
         rect(x, y, w, h)
     }
 
@@ -39,6 +79,17 @@ impl Rect {
         rect(0.0, 0.0, w, h)
     }
 
+    /// Create the smallest rectangle containing all the points, or an empty rectangle if
+    /// there are none.
+    pub fn from_points(points: &[Vec2]) -> Self {
+        match points.split_first() {
+            Some((first, rest)) => rest.iter().fold(rect(first.x, first.y, 0.0, 0.0), |r, p| {
+                r.expanded_to_include(*p)
+            }),
+            None => Self::EMPTY,
+        }
+    }
+
     pub fn size(&self) -> Vec2 {
         vec2(self.w, self.h)
     }
@@ -127,6 +178,26 @@ impl Rect {
         vec2(self.right(), self.center_y())
     }
 
+    /// The four corners, clockwise from the top-left.
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// The four corners, counter-clockwise from the top-left.
+    pub fn corners_ccw(&self) -> [Vec2; 4] {
+        [
+            self.top_left(),
+            self.bottom_left(),
+            self.bottom_right(),
+            self.top_right(),
+        ]
+    }
+
     pub fn area(&self) -> f32 {
         self.w * self.h
     }
@@ -135,10 +206,21 @@ impl Rect {
         self.w * 2.0 + self.h * 2.0
     }
 
+    /// Check if two rectangles are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
     pub fn contains(&self, p: Vec2) -> bool {
         p.x >= self.x && p.y >= self.y && p.x < self.right() && p.y < self.bottom()
     }
 
+    /// Like [contains()](#method.contains), but points on the right and bottom edges are
+    /// inside too, treating the rectangle as a closed region.
+    pub fn contains_inclusive(&self, p: Vec2) -> bool {
+        p.x >= self.x && p.y >= self.y && p.x <= self.right() && p.y <= self.bottom()
+    }
+
     pub fn contains_rect(&self, r: &Rect) -> bool {
         r.x >= self.x && r.y >= self.y && r.right() <= self.right() && r.bottom() <= self.bottom()
     }
@@ -157,6 +239,100 @@ impl Rect {
         }
     }
 
+    /// Cast a ray against the rectangle, returning how far along `dir` it first touches it.
+    /// Rays that start inside the rectangle hit it immediately, at `0.0`.
+    pub fn ray_intersect(&self, origin: Vec2, dir: Vec2) -> Option<f32> {
+        let min = self.min();
+        let max = self.max();
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+        for i in 0..2 {
+            if dir[i] == 0.0 {
+                if origin[i] < min[i] || origin[i] > max[i] {
+                    return None;
+                }
+            } else {
+                let a = (min[i] - origin[i]) / dir[i];
+                let b = (max[i] - origin[i]) / dir[i];
+                near = near.max(a.min(b));
+                far = far.min(a.max(b));
+                if near > far {
+                    return None;
+                }
+            }
+        }
+        Some(near)
+    }
+
+    /// Check if the line segment from `a` to `b` touches the rectangle.
+    pub fn segment_intersect(&self, a: Vec2, b: Vec2) -> bool {
+        matches!(self.ray_intersect(a, b - a), Some(t) if t <= 1.0)
+    }
+
+    /// Sweep a circle of `radius` from `from` to `to`, returning the time in range
+    /// (0.0 - 1.0) it first touches the rectangle and the contact normal pointing out of it.
+    /// Circles that already touch it at `from` hit immediately, with a zero normal if their
+    /// center is inside the rectangle.
+    pub fn sweep_circle(&self, radius: f32, from: Vec2, to: Vec2) -> Option<(f32, Vec2)> {
+        let min = self.min();
+        let max = self.max();
+        let closest = from.clamp(min, max);
+        if from.sqr_dist(closest) <= radius * radius {
+            let normal = match from == closest {
+                true => Vec2::ZERO,
+                false => (from - closest).norm(),
+            };
+            return Some((0.0, normal));
+        }
+
+        // Ray cast against the rectangle expanded by the radius
+        let dir = to - from;
+        let mut near = 0.0f32;
+        let mut far = 1.0f32;
+        let mut normal = Vec2::ZERO;
+        for i in 0..2 {
+            if dir[i] == 0.0 {
+                if from[i] < min[i] - radius || from[i] > max[i] + radius {
+                    return None;
+                }
+            } else {
+                let a = (min[i] - radius - from[i]) / dir[i];
+                let b = (max[i] + radius - from[i]) / dir[i];
+                if a.min(b) > near {
+                    near = a.min(b);
+                    normal = match i {
+                        0 => vec2(-dir.x.signum(), 0.0),
+                        _ => vec2(0.0, -dir.y.signum()),
+                    };
+                }
+                far = far.min(a.max(b));
+                if near > far {
+                    return None;
+                }
+            }
+        }
+
+        // If the hit lands in a corner region, the expanded rectangle is rounded there
+        let p = from + dir * near;
+        let corner = p.clamp(min, max);
+        if (p.x < min.x || p.x > max.x) && (p.y < min.y || p.y > max.y) {
+            let m = from - corner;
+            let a = dir.dot(dir);
+            let b = m.dot(dir);
+            let c = m.dot(m) - radius * radius;
+            let disc = b * b - a * c;
+            if disc < 0.0 {
+                return None;
+            }
+            let t = (-b - disc.sqrt()) / a;
+            return match (0.0..=1.0).contains(&t) {
+                true => Some((t, (from + dir * t - corner) / radius)),
+                false => None,
+            };
+        }
+        Some((near, normal))
+    }
+
     pub fn scale_to_fit(&self, outer: &Self) -> Self {
         let s = (outer.w / self.w).min(outer.h / self.h);
         let w = self.w * s;
@@ -164,6 +340,62 @@ impl Rect {
         rect((outer.w - w) * 0.5, (outer.h - h) * 0.5, w, h)
     }
 
+    /// Move the rectangle, keeping its size, so it sits against the `anchor` point of `parent`.
+    pub fn aligned_in(&self, parent: &Self, anchor: Anchor) -> Self {
+        self.aligned_in_with_margin(parent, anchor, 0.0)
+    }
+
+    /// Same as [aligned_in()](#method.aligned_in), but keeping `margin` away from the edges
+    /// of `parent`.
+    pub fn aligned_in_with_margin(&self, parent: &Self, anchor: Anchor, margin: f32) -> Self {
+        let f = anchor.factor();
+        rect(
+            parent.x + margin + (parent.w - margin * 2.0 - self.w) * f.x,
+            parent.y + margin + (parent.h - margin * 2.0 - self.h) * f.y,
+            self.w,
+            self.h,
+        )
+    }
+
+    /// The smallest integer rectangle that encloses this one.
+    pub fn round_out(&self) -> IntRect {
+        let (x, y) = (self.min_x().floor(), self.min_y().floor());
+        let (r, b) = (self.max_x().ceil(), self.max_y().ceil());
+        irect(x as i32, y as i32, (r - x) as i32, (b - y) as i32)
+    }
+
+    /// The largest integer rectangle contained within this one, which is empty on any axis
+    /// where it doesn't cover a whole unit.
+    pub fn round_in(&self) -> IntRect {
+        let (x, y) = (self.min_x().ceil(), self.min_y().ceil());
+        let (r, b) = (self.max_x().floor().max(x), self.max_y().floor().max(y));
+        irect(x as i32, y as i32, (r - x) as i32, (b - y) as i32)
+    }
+
+    /// Round the rectangle's edges to the nearest whole numbers.
+    pub fn round_nearest(&self) -> IntRect {
+        let (x, y) = (self.min_x().round(), self.min_y().round());
+        let (r, b) = (self.max_x().round(), self.max_y().round());
+        irect(x as i32, y as i32, (r - x) as i32, (b - y) as i32)
+    }
+
+    /// Move the rectangle the least amount needed for it to fit inside `bounds`. If it is
+    /// larger than `bounds` on an axis, it is centered on that axis instead.
+    pub fn clamped_inside(&self, bounds: &Self) -> Self {
+        fn clamp(x: f32, w: f32, min: f32, size: f32) -> f32 {
+            match w > size {
+                true => min + (size - w) * 0.5,
+                false => x.max(min).min(min + size - w),
+            }
+        }
+        rect(
+            clamp(self.x, self.w, bounds.x, bounds.w),
+            clamp(self.y, self.h, bounds.y, bounds.h),
+            self.w,
+            self.h,
+        )
+    }
+
     pub fn conflate(&self, r: &Self) -> Self {
         let x = self.min_x().min(r.min_x());
         let y = self.min_y().min(r.min_y());
@@ -172,6 +404,18 @@ impl Rect {
         rect(x, y, w - x, h - y)
     }
 
+    /// The smallest rectangle containing both this rectangle and the point.
+    pub fn expanded_to_include(&self, p: Vec2) -> Self {
+        let min = self.min().min(p);
+        let max = self.max().max(p);
+        rect(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    /// Grow the rectangle to contain the point.
+    pub fn expand_to_include(&mut self, p: Vec2) {
+        *self = self.expanded_to_include(p);
+    }
+
     pub fn translate(&self, amount: Vec2) -> Self {
         rect(self.x + amount.x, self.y + amount.y, self.w, self.h)
     }
@@ -180,6 +424,23 @@ impl Rect {
         rect(self.x - w * 0.5, self.y - h * 0.5, self.w + w, self.h + h)
     }
 
+    /// Linear interpolation between the position and size of two rectangles by a factor `t`.
+    pub fn lerp(&self, other: &Rect, t: f32) -> Self {
+        rect(
+            crate::lerp(self.x, other.x, t),
+            crate::lerp(self.y, other.y, t),
+            crate::lerp(self.w, other.w, t),
+            crate::lerp(self.h, other.h, t),
+        )
+    }
+
+    /// Interpolate the min and max corners of the rectangles, rather than their position and size.
+    pub fn lerp_bounds(&self, other: &Rect, t: f32) -> Self {
+        let min = self.min().lerp(other.min(), t);
+        let max = self.max().lerp(other.max(), t);
+        rect(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
     pub fn non_neg(&self) -> Self {
         let mut r = *self;
         if r.w < 0.0 {
@@ -192,11 +453,45 @@ impl Rect {
         }
         r
     }
+
+    /// Divide the rectangle into a `cols` by `rows` grid of cells, in row-major order.
+    pub fn grid(&self, cols: u32, rows: u32) -> Vec<Rect> {
+        if cols == 0 || rows == 0 {
+            return Vec::new();
+        }
+        let w = self.w / cols as f32;
+        let h = self.h / rows as f32;
+        let mut cells = Vec::with_capacity((cols * rows) as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                cells.push(rect(self.x + w * col as f32, self.y + h * row as f32, w, h));
+            }
+        }
+        cells
+    }
+
+    /// Cut the rectangle into a left and right half, at fraction `t` of its width.
+    pub fn split_h(&self, t: f32) -> (Rect, Rect) {
+        let w = self.w * t;
+        (
+            rect(self.x, self.y, w, self.h),
+            rect(self.x + w, self.y, self.w - w, self.h),
+        )
+    }
+
+    /// Cut the rectangle into a top and bottom half, at fraction `t` of its height.
+    pub fn split_v(&self, t: f32) -> (Rect, Rect) {
+        let h = self.h * t;
+        (
+            rect(self.x, self.y, self.w, h),
+            rect(self.x, self.y + h, self.w, self.h - h),
+        )
+    }
 }
 
 impl AsRef<[f32]> for Rect {
     fn as_ref(&self) -> &[f32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const f32, 4) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 4) }
     }
 }
 
@@ -290,3 +585,185 @@ impl DivAssign<f32> for Rect {
         *self = self.div(rhs);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{irect, vec2, Mat3x2};
+
+    #[test]
+    fn grid_tiles_rect() {
+        let r = rect(10.0, 20.0, 90.0, 60.0);
+        let cells = r.grid(3, 2);
+        assert_eq!(cells.len(), 6);
+        assert!(cells[0].approx_eq(&rect(10.0, 20.0, 30.0, 30.0)));
+        assert!(cells[5].approx_eq(&rect(70.0, 50.0, 30.0, 30.0)));
+        let area: f32 = cells.iter().map(|c| c.area()).sum();
+        assert!(crate::approx_f32(area, r.area()));
+        for (i, a) in cells.iter().enumerate() {
+            for b in &cells[i + 1..] {
+                assert!(a.overlap(b).filter(|o| o.area() > 0.0).is_none());
+            }
+        }
+        assert!(r.grid(0, 2).is_empty());
+    }
+
+    #[test]
+    fn split_at_fraction() {
+        let r = rect(10.0, 20.0, 100.0, 50.0);
+        let (left, right) = r.split_h(0.25);
+        assert!(left.approx_eq(&rect(10.0, 20.0, 25.0, 50.0)));
+        assert!(right.approx_eq(&rect(35.0, 20.0, 75.0, 50.0)));
+        let (top, bottom) = r.split_v(0.4);
+        assert!(top.approx_eq(&rect(10.0, 20.0, 100.0, 20.0)));
+        assert!(bottom.approx_eq(&rect(10.0, 40.0, 100.0, 30.0)));
+    }
+
+    #[test]
+    fn ray_hit_miss_and_parallel() {
+        let r = rect(10.0, 10.0, 20.0, 20.0);
+        let hit = r.ray_intersect(vec2(0.0, 20.0), vec2(1.0, 0.0));
+        assert!(crate::approx_f32(hit.unwrap(), 10.0));
+        assert_eq!(r.ray_intersect(vec2(0.0, 20.0), vec2(-1.0, 0.0)), None);
+        assert_eq!(r.ray_intersect(vec2(0.0, 0.0), vec2(1.0, 0.0)), None);
+        assert_eq!(r.ray_intersect(vec2(20.0, 20.0), vec2(0.0, 1.0)), Some(0.0));
+        assert!(r.segment_intersect(vec2(0.0, 15.0), vec2(15.0, 15.0)));
+        assert!(!r.segment_intersect(vec2(0.0, 15.0), vec2(5.0, 15.0)));
+    }
+
+    #[test]
+    fn corners_winding() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        let [a, b, c, d] = r.corners();
+        assert_eq!(
+            [a, b, c, d],
+            [
+                vec2(1.0, 2.0),
+                vec2(4.0, 2.0),
+                vec2(4.0, 6.0),
+                vec2(1.0, 6.0)
+            ]
+        );
+        assert_eq!(r.corners_ccw(), [a, d, c, b]);
+        let m = Mat3x2::translation(vec2(5.0, -1.0)).mult(&Mat3x2::scale(vec2(2.0, 2.0)));
+        let moved = r.corners().map(|p| m.transform(p));
+        let back = m.invert().transform_rect(Rect::from_points(&moved));
+        assert!(back.approx_eq(&r));
+    }
+
+    #[test]
+    fn lerp_halfway() {
+        let small = rect(0.0, 0.0, 10.0, 10.0);
+        let large = rect(20.0, 40.0, 30.0, 50.0);
+        let mid = rect(10.0, 20.0, 20.0, 30.0);
+        assert!(small.lerp(&large, 0.5).approx_eq(&mid));
+        assert!(small.lerp_bounds(&large, 0.5).approx_eq(&mid));
+        assert!(small.lerp(&large, 0.0).approx_eq(&small));
+        assert!(small.lerp_bounds(&large, 1.0).approx_eq(&large));
+    }
+
+    #[test]
+    fn clamped_inside_bounds() {
+        let bounds = rect(0.0, 0.0, 100.0, 50.0);
+        let shifted = rect(90.0, 10.0, 20.0, 20.0).clamped_inside(&bounds);
+        assert!(shifted.approx_eq(&rect(80.0, 10.0, 20.0, 20.0)));
+        let wide = rect(-30.0, -5.0, 120.0, 10.0).clamped_inside(&bounds);
+        assert!(wide.approx_eq(&rect(-10.0, 0.0, 120.0, 10.0)));
+        let nan = rect(10.0, 10.0, f32::NAN, 5.0).clamped_inside(&bounds);
+        assert_eq!(nan.y, 10.0);
+    }
+
+    #[test]
+    fn bounds_from_scattered_points() {
+        let points = [
+            vec2(3.0, 1.0),
+            vec2(-2.0, 4.0),
+            vec2(5.0, -1.0),
+            vec2(0.0, 2.0),
+        ];
+        let bounds = Rect::from_points(&points);
+        assert!(bounds.approx_eq(&rect(-2.0, -1.0, 7.0, 5.0)));
+        let mut r = rect(0.0, 0.0, 1.0, 1.0);
+        for p in &points {
+            r.expand_to_include(*p);
+        }
+        assert!(r.approx_eq(&bounds));
+        assert!(Rect::from_points(&[]).approx_eq(&Rect::EMPTY));
+    }
+
+    #[test]
+    fn aligned_to_corners() {
+        let parent = rect(0.0, 0.0, 100.0, 100.0);
+        let child = rect(5.0, 5.0, 10.0, 10.0);
+        let cases = [
+            (Anchor::TopLeft, vec2(0.0, 0.0)),
+            (Anchor::TopRight, vec2(90.0, 0.0)),
+            (Anchor::BottomLeft, vec2(0.0, 90.0)),
+            (Anchor::BottomRight, vec2(90.0, 90.0)),
+            (Anchor::Center, vec2(45.0, 45.0)),
+            (Anchor::TopCenter, vec2(45.0, 0.0)),
+        ];
+        for &(anchor, pos) in &cases {
+            let r = child.aligned_in(&parent, anchor);
+            assert!(r.approx_eq(&rect(pos.x, pos.y, 10.0, 10.0)));
+        }
+        let r = child.aligned_in_with_margin(&parent, Anchor::BottomRight, 4.0);
+        assert!(r.approx_eq(&rect(86.0, 86.0, 10.0, 10.0)));
+    }
+
+    #[test]
+    fn round_to_int_rect() {
+        let r = rect(0.3, 0.3, 1.4, 1.4);
+        assert_eq!(r.round_out(), irect(0, 0, 2, 2));
+        assert_eq!(r.round_in(), irect(1, 1, 0, 0));
+        assert_eq!(r.round_nearest(), irect(0, 0, 2, 2));
+
+        let r = rect(-1.5, 0.2, 3.0, 2.6);
+        assert_eq!(r.round_out(), irect(-2, 0, 4, 3));
+        assert_eq!(r.round_in(), irect(-1, 1, 2, 1));
+
+        let r: Rect = irect(3, -4, 5, 6).into();
+        assert_eq!(r.round_out(), irect(3, -4, 5, 6));
+        assert_eq!(r.round_in(), irect(3, -4, 5, 6));
+    }
+
+    #[test]
+    fn sweep_circle_hits_and_misses() {
+        let wall = rect(10.0, 0.0, 2.0, 10.0);
+
+        let (t, n) = wall
+            .sweep_circle(1.0, vec2(0.0, 5.0), vec2(20.0, 5.0))
+            .unwrap();
+        assert!((t - 0.45).abs() < 1e-5);
+        assert_eq!(n, vec2(-1.0, 0.0));
+
+        // too fast to overlap at either end, but still caught
+        let hit = wall.sweep_circle(0.5, vec2(5.0, 5.0), vec2(17.0, 5.0));
+        assert!(hit.is_some());
+
+        assert_eq!(
+            wall.sweep_circle(1.0, vec2(0.0, 12.0), vec2(20.0, 12.0)),
+            None
+        );
+        assert_eq!(wall.sweep_circle(1.0, vec2(0.0, 5.0), vec2(8.0, 5.0)), None);
+        assert_eq!(wall.sweep_circle(1.0, vec2(8.0, 5.0), vec2(0.0, 5.0)), None);
+
+        let (t, _) = wall
+            .sweep_circle(1.0, vec2(9.5, 5.0), vec2(20.0, 5.0))
+            .unwrap();
+        assert_eq!(t, 0.0);
+    }
+
+    #[test]
+    fn contains_inclusive_edges() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        for p in [vec2(4.0, 3.0), vec2(2.0, 6.0), vec2(4.0, 6.0)] {
+            assert!(!r.contains(p));
+            assert!(r.contains_inclusive(p));
+        }
+        assert!(r.contains(vec2(1.0, 2.0)));
+        assert!(r.contains_inclusive(vec2(1.0, 2.0)));
+        assert!(!r.contains_inclusive(vec2(4.01, 3.0)));
+        assert!(!r.contains_inclusive(vec2(0.99, 3.0)));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Rect, Vec2};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 2D line segment, running from `a` to `b`.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Segment2 {
+    pub a: Vec2,
+    pub b: Vec2,
+}
+
+/// Easy constructor.
+pub const fn segment2(a: Vec2, b: Vec2) -> Segment2 {
+    Segment2 { a, b }
+}
+
+impl Segment2 {
+    /// Create a new segment.
+    pub const fn new(a: Vec2, b: Vec2) -> Self {
+        segment2(a, b)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.a.dist(self.b)
+    }
+
+    /// The normalized direction from `a` to `b`.
+    pub fn direction(&self) -> Vec2 {
+        (self.b - self.a).norm()
+    }
+
+    /// The point on the segment closest to `p`.
+    pub fn closest_point(&self, p: Vec2) -> Vec2 {
+        p.closest_point_on_segment(self.a, self.b)
+    }
+
+    /// The distance from `p` to the closest point on the segment.
+    pub fn distance_to(&self, p: Vec2) -> f32 {
+        p.dist_to_segment(self.a, self.b)
+    }
+
+    /// The point where both segments cross. Parallel segments, including overlapping
+    /// collinear ones, never intersect. Parallelism is checked relative to the segments'
+    /// lengths, so it behaves the same at any scale.
+    pub fn intersect(&self, other: &Segment2) -> Option<Vec2> {
+        let d1 = self.b - self.a;
+        let d2 = other.b - other.a;
+        let denom = d1.cross(d2);
+        if denom.abs() <= f32::EPSILON * d1.len() * d2.len() {
+            return None;
+        }
+        let diff = other.a - self.a;
+        let t = diff.cross(d2) / denom;
+        let u = diff.cross(d1) / denom;
+        match (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            true => Some(self.a + d1 * t),
+            false => None,
+        }
+    }
+
+    /// The parameters in range (0.0 - 1.0) where the segment enters and exits the rectangle.
+    /// If `a` is already inside, the entry is `0.0`, and if `b` is inside, the exit is `1.0`.
+    pub fn intersect_rect(&self, r: &Rect) -> Option<(f32, f32)> {
+        let min = r.min();
+        let max = r.max();
+        let dir = self.b - self.a;
+        let mut near = 0.0f32;
+        let mut far = 1.0f32;
+        for i in 0..2 {
+            if dir[i] == 0.0 {
+                if self.a[i] < min[i] || self.a[i] > max[i] {
+                    return None;
+                }
+            } else {
+                let a = (min[i] - self.a[i]) / dir[i];
+                let b = (max[i] - self.a[i]) / dir[i];
+                near = near.max(a.min(b));
+                far = far.min(a.max(b));
+                if near > far {
+                    return None;
+                }
+            }
+        }
+        Some((near, far))
+    }
+}
+
+impl AsRef<[f32]> for Segment2 {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 4) }
+    }
+}
+
+impl fmt::Display for Segment2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rect, vec2};
+
+    #[test]
+    fn intersect_crossing_and_parallel() {
+        let a = segment2(vec2(0.0, 0.0), vec2(4.0, 4.0));
+        let b = segment2(vec2(0.0, 4.0), vec2(4.0, 0.0));
+        assert_eq!(a.intersect(&b), Some(vec2(2.0, 2.0)));
+        assert_eq!(b.intersect(&a), Some(vec2(2.0, 2.0)));
+
+        let c = segment2(vec2(1.0, 0.0), vec2(5.0, 4.0));
+        assert_eq!(a.intersect(&c), None);
+        assert_eq!(a.intersect(&a), None);
+
+        let short = segment2(vec2(0.0, 4.0), vec2(1.0, 3.0));
+        assert_eq!(a.intersect(&short), None);
+    }
+
+    #[test]
+    fn intersect_is_scale_independent() {
+        let s = 1e-4;
+        let a = segment2(vec2(0.0, 0.0), vec2(s, s));
+        let b = segment2(vec2(0.0, s), vec2(s, 0.0));
+        let p = a.intersect(&b).unwrap();
+        assert!(p.max_abs_diff(vec2(s, s) * 0.5) < 1e-9);
+    }
+
+    #[test]
+    fn intersect_rect_params() {
+        let r = rect(1.0, 1.0, 2.0, 2.0);
+        let s = segment2(vec2(0.0, 2.0), vec2(4.0, 2.0));
+        assert_eq!(s.intersect_rect(&r), Some((0.25, 0.75)));
+        let inside = segment2(vec2(2.0, 2.0), vec2(2.5, 2.5));
+        assert_eq!(inside.intersect_rect(&r), Some((0.0, 1.0)));
+        let miss = segment2(vec2(0.0, 0.0), vec2(4.0, 0.5));
+        assert_eq!(miss.intersect_rect(&r), None);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{vec3, Vec2, Vec3};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 2D triangle, defined by its three corners.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Triangle2 {
+    pub a: Vec2,
+    pub b: Vec2,
+    pub c: Vec2,
+}
+
+/// Easy constructor.
+pub const fn triangle2(a: Vec2, b: Vec2, c: Vec2) -> Triangle2 {
+    Triangle2 { a, b, c }
+}
+
+impl Triangle2 {
+    /// Create a new triangle.
+    pub const fn new(a: Vec2, b: Vec2, c: Vec2) -> Self {
+        triangle2(a, b, c)
+    }
+
+    /// The area of the triangle, positive if `a -> b -> c` turns from +x towards +y.
+    pub fn signed_area(&self) -> f32 {
+        crate::signed_area(self.a, self.b, self.c)
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    pub fn centroid(&self) -> Vec2 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// The barycentric weights of `a`, `b` and `c` that add up to the point `p`, which all
+    /// fall in range (0.0 - 1.0) for points inside the triangle. Degenerate triangles with
+    /// no area give non-finite weights.
+    pub fn barycentric(&self, p: Vec2) -> Vec3 {
+        let area = self.signed_area();
+        let u = crate::signed_area(p, self.b, self.c) / area;
+        let v = crate::signed_area(self.a, p, self.c) / area;
+        vec3(u, v, 1.0 - u - v)
+    }
+
+    /// The point at the barycentric weights `w` of `a`, `b` and `c`.
+    pub fn from_barycentric(&self, w: Vec3) -> Vec2 {
+        self.a * w.x + self.b * w.y + self.c * w.z
+    }
+
+    /// Check if the point is inside the triangle or on its edges.
+    pub fn contains(&self, p: Vec2) -> bool {
+        let w = self.barycentric(p);
+        w.is_finite() && w.x >= 0.0 && w.y >= 0.0 && w.z >= 0.0
+    }
+}
+
+impl AsRef<[f32]> for Triangle2 {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 6) }
+    }
+}
+
+impl fmt::Display for Triangle2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.a, self.b, self.c)
+    }
+}
+
+/// A 3D triangle, defined by its three corners.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+#[repr(C)]
+pub struct Triangle3 {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+/// Easy constructor.
+pub const fn triangle3(a: Vec3, b: Vec3, c: Vec3) -> Triangle3 {
+    Triangle3 { a, b, c }
+}
+
+impl Triangle3 {
+    /// Create a new triangle.
+    pub const fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        triangle3(a, b, c)
+    }
+
+    pub fn area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a).len() * 0.5
+    }
+
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// The normalized direction the triangle faces. Looking at the triangle from that side,
+    /// the corners wind counter-clockwise.
+    pub fn normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).norm()
+    }
+
+    /// The barycentric weights of `a`, `b` and `c` that add up to the point `p`, after
+    /// projecting it onto the triangle's plane. Degenerate triangles with no area give
+    /// non-finite weights.
+    pub fn barycentric(&self, p: Vec3) -> Vec3 {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = p - self.a;
+        let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+        let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+        let denom = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        vec3(1.0 - v - w, v, w)
+    }
+
+    /// The point at the barycentric weights `w` of `a`, `b` and `c`.
+    pub fn from_barycentric(&self, w: Vec3) -> Vec3 {
+        self.a * w.x + self.b * w.y + self.c * w.z
+    }
+}
+
+impl AsRef<[f32]> for Triangle3 {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 9) }
+    }
+}
+
+impl fmt::Display for Triangle3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.a, self.b, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec2;
+
+    #[test]
+    fn triangle2_barycentric() {
+        let t = triangle2(vec2(0.0, 0.0), vec2(4.0, 0.0), vec2(1.0, 3.0));
+        assert_eq!(t.area(), 6.0);
+
+        let w = t.barycentric(t.centroid());
+        for c in [w.x, w.y, w.z] {
+            assert!((c - 1.0 / 3.0).abs() < 1e-5);
+        }
+        assert_eq!(t.barycentric(t.b), vec3(0.0, 1.0, 0.0));
+        let p = t.from_barycentric(vec3(0.2, 0.5, 0.3));
+        let w = t.barycentric(p);
+        assert!(w.max_abs_diff(vec3(0.2, 0.5, 0.3)) < 1e-5);
+
+        assert!(t.contains(t.centroid()));
+        assert!(t.contains(t.a));
+        assert!(!t.contains(vec2(-0.1, 0.0)));
+        assert!(!t.contains(vec2(3.0, 3.0)));
+        assert!(!t.contains(vec2(2.0, -1.0)));
+
+        let flat = triangle2(vec2(0.0, 0.0), vec2(1.0, 1.0), vec2(2.0, 2.0));
+        assert!(!flat.contains(vec2(1.0, 1.0)));
+    }
+
+    #[test]
+    fn triangle3_normal_and_barycentric() {
+        let t = triangle3(
+            vec3(0.0, 0.0, 0.0),
+            vec3(2.0, 0.0, 0.0),
+            vec3(0.0, 2.0, 0.0),
+        );
+        assert_eq!(t.normal(), vec3(0.0, 0.0, 1.0));
+        assert_eq!(t.area(), 2.0);
+        let w = t.barycentric(t.centroid() + vec3(0.0, 0.0, 5.0));
+        for c in [w.x, w.y, w.z] {
+            assert!((c - 1.0 / 3.0).abs() < 1e-5);
+        }
+    }
+}
@@ -1,13 +1,25 @@
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Neg, Sub, AddAssign, SubAssign, MulAssign, DivAssign, Rem, RemAssign, Index};
-use crate::{Vec3};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Radians, Vec3};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 /// A two-dimensional floating point vector.
 #[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Vec2 {
     pub x: f32,
@@ -15,7 +27,7 @@ pub struct Vec2 {
 }
 
 /// Easy constructor.
-pub fn vec2(x: f32, y: f32) -> Vec2 {
+pub const fn vec2(x: f32, y: f32) -> Vec2 {
     Vec2 { x, y }
 }
 
@@ -40,10 +52,38 @@ impl Vec2 {
     pub const UP: Self = Self { x: 0.0, y: -1.0 };
 
     /// Create a new vector.
-    pub fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: f32, y: f32) -> Self {
         vec2(x, y)
     }
 
+    /// The vector's components as an array.
+    pub fn to_array(&self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+
+    /// Create a new vector from an array of components.
+    pub fn from_array(a: [f32; 2]) -> Self {
+        vec2(a[0], a[1])
+    }
+
+    /// Read the vector from the first 2 floats of `s`. Panics if `s` is too short.
+    pub fn from_slice(s: &[f32]) -> Self {
+        vec2(s[0], s[1])
+    }
+
+    /// Read the vector from the first 2 floats of `s`, or `None` if `s` is too short.
+    pub fn try_from_slice(s: &[f32]) -> Option<Self> {
+        match s.len() >= 2 {
+            true => Some(Self::from_slice(s)),
+            false => None,
+        }
+    }
+
+    /// Write the vector into the first 2 floats of `s`. Panics if `s` is too short.
+    pub fn write_to_slice(&self, s: &mut [f32]) {
+        s[..2].copy_from_slice(&self.to_array());
+    }
+
     /// Extend this vector with a z-axis.
     pub fn extend(&self, z: f32) -> Vec3 {
         crate::vec3(self.x, self.y, z)
@@ -54,6 +94,29 @@ impl Vec2 {
         vec2(rad.cos(), rad.sin())
     }
 
+    /// One of the 8 compass directions as a unit vector, starting with `Vec2::RIGHT` at 0
+    /// and turning 45º in the same direction as [polar()](#method.polar) for each step.
+    /// Indices wrap around after 7.
+    pub fn direction_8(index: u8) -> Self {
+        const D: f32 = core::f32::consts::FRAC_1_SQRT_2;
+        match index % 8 {
+            0 => vec2(1.0, 0.0),
+            1 => vec2(D, D),
+            2 => vec2(0.0, 1.0),
+            3 => vec2(-D, D),
+            4 => vec2(-1.0, 0.0),
+            5 => vec2(-D, -D),
+            6 => vec2(0.0, -1.0),
+            _ => vec2(D, -D),
+        }
+    }
+
+    /// Create a random point on the unit circle, uniformly distributed by angle.
+    #[cfg(feature = "rand")]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::polar(rng.gen::<f32>() * crate::TAU)
+    }
+
     /// The length of the vector, squared.
     pub fn sqr_len(&self) -> f32 {
         self.x * self.x + self.y * self.y
@@ -64,11 +127,49 @@ impl Vec2 {
         self.sqr_len().sqrt()
     }
 
+    /// The manhattan length of the vector, which is the sum of its absolute components.
+    pub fn manhattan_len(&self) -> f32 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// The chebyshev length of the vector, which is its largest absolute component.
+    pub fn chebyshev_len(&self) -> f32 {
+        self.abs().max_element()
+    }
+
     /// Get the angle of the vector in radians.
     pub fn angle(&self) -> f32 {
         self.y.atan2(self.x)
     }
 
+    /// Get the signed angle in radians to rotate this vector's direction onto `other`'s,
+    /// in range (-π - π).
+    pub fn angle_to(&self, other: Self) -> f32 {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Rotate the vector by `angle` in the same direction as [Vec2::polar()](#method.polar).
+    pub fn rotate(&self, angle: impl Into<Radians>) -> Self {
+        let Radians(a) = angle.into();
+        let (s, c) = (a.sin(), a.cos());
+        vec2(self.x * c - self.y * s, self.x * s + self.y * c)
+    }
+
+    /// Rotate the vector towards `target`'s direction by at most `max_angle`, keeping its
+    /// length. If the remaining angle is smaller than `max_angle`, it lands exactly on it.
+    pub fn rotate_towards(&self, target: Self, max_angle: impl Into<Radians>) -> Self {
+        let Radians(max) = max_angle.into();
+        let a = self.angle_to(target);
+        if a.abs() <= max.abs() {
+            match target.sqr_len() > 0.0 {
+                true => target * (self.len() / target.len()),
+                false => *self,
+            }
+        } else {
+            self.rotate(max.abs() * a.signum())
+        }
+    }
+
     /// Barycentric coordinate.
     pub fn bary(a: Self, b: Self, c: Self, t1: f32, t2: f32) -> Self {
         vec2(
@@ -77,12 +178,59 @@ impl Vec2 {
         )
     }
 
+    /// Check if all of the vector's components are finite, neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Check if any of the vector's components are NaN.
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Check if two vectors are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
+    /// The absolute difference between each of the vectors' components.
+    pub fn abs_diff(&self, other: Self) -> Self {
+        (*self - other).abs()
+    }
+
+    /// The largest absolute difference between any of the vectors' components.
+    pub fn max_abs_diff(&self, other: Self) -> f32 {
+        self.abs_diff(other).max_element()
+    }
+
     /// Normalize the vector.
     pub fn norm(&self) -> Self {
         let len = self.len();
         vec2(self.x / len, self.y / len)
     }
 
+    /// Normalize the vector, also returning its length from before. The zero vector returns
+    /// `(ZERO, 0.0)`.
+    pub fn norm_and_len(&self) -> (Self, f32) {
+        let len = self.len();
+        match len > 0.0 {
+            true => (vec2(self.x / len, self.y / len), len),
+            false => (Self::ZERO, 0.0),
+        }
+    }
+
+    /// Snap the vector's direction to the nearest of `RIGHT`, `DOWN`, `LEFT` and `UP`. The
+    /// zero vector stays zero.
+    pub fn nearest_cardinal(&self) -> Self {
+        if self.x == 0.0 && self.y == 0.0 {
+            Self::ZERO
+        } else if self.x.abs() >= self.y.abs() {
+            vec2(self.x.signum(), 0.0)
+        } else {
+            vec2(0.0, self.y.signum())
+        }
+    }
+
     /// Rotate the vector 90º left, creating a perpendicular vector.
     pub fn turn_left(&self) -> Self {
         vec2(self.y, -self.x)
@@ -103,6 +251,16 @@ impl Vec2 {
         vec2(0.0, self.y)
     }
 
+    /// Apply a function to each of the vector's components.
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        vec2(f(self.x), f(self.y))
+    }
+
+    /// Combine the components of two vectors pairwise with a function.
+    pub fn zip_with(&self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        vec2(f(self.x, other.x), f(self.y, other.y))
+    }
+
     /// Absolute the vector's components.
     pub fn abs(&self) -> Self {
         vec2(self.x.abs(), self.y.abs())
@@ -123,6 +281,34 @@ impl Vec2 {
         vec2(self.x.round(), self.y.round())
     }
 
+    /// Round the vector's components down, converting them to integers.
+    pub fn floor_to_int2(&self) -> crate::Int2 {
+        crate::int2(self.x.floor() as i32, self.y.floor() as i32)
+    }
+
+    /// Round the vector's components up, converting them to integers.
+    pub fn ceil_to_int2(&self) -> crate::Int2 {
+        crate::int2(self.x.ceil() as i32, self.y.ceil() as i32)
+    }
+
+    /// Round the vector's components to the nearest whole number (half-way cases away from zero), converting them to integers.
+    pub fn round_to_int2(&self) -> crate::Int2 {
+        crate::int2(self.x.round() as i32, self.y.round() as i32)
+    }
+
+    /// Round the vector's components to the nearest multiple of `step`.
+    /// Axes with a step of zero are left unchanged.
+    pub fn snap(&self, step: impl Into<Vec2>) -> Self {
+        fn snap(v: f32, step: f32) -> f32 {
+            match step == 0.0 {
+                true => v,
+                false => (v / step).round() * step,
+            }
+        }
+        let step = step.into();
+        vec2(snap(self.x, step.x), snap(self.y, step.y))
+    }
+
     /// Return the minimum of the vector's components.
     pub fn min(&self, other: Self) -> Self {
         vec2(self.x.min(other.x), self.y.min(other.y))
@@ -133,6 +319,47 @@ impl Vec2 {
         vec2(self.x.max(other.x), self.y.max(other.y))
     }
 
+    /// Return the smallest of the vector's components.
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y)
+    }
+
+    /// Return the largest of the vector's components.
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y)
+    }
+
+    /// The smallest value of each component across all of the vectors, or `ZERO` if there
+    /// are none.
+    pub fn min_components(vs: &[Self]) -> Self {
+        match vs.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |a, b| a.min(*b)),
+            None => Self::ZERO,
+        }
+    }
+
+    /// The largest value of each component across all of the vectors, or `ZERO` if there
+    /// are none.
+    pub fn max_components(vs: &[Self]) -> Self {
+        match vs.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |a, b| a.max(*b)),
+            None => Self::ZERO,
+        }
+    }
+
+    /// The average of all of the vectors, or `ZERO` if there are none.
+    pub fn centroid(vs: &[Self]) -> Self {
+        match vs.is_empty() {
+            true => Self::ZERO,
+            false => vs.iter().fold(Self::ZERO, |a, b| a + *b) / vs.len() as f32,
+        }
+    }
+
+    /// Return the reciprocal of the vector's components.
+    pub fn recip(&self) -> Self {
+        vec2(1.0 / self.x, 1.0 / self.y)
+    }
+
     /// Sign the vector's components.
     pub fn sign(&self) -> Self {
         vec2(crate::sign(self.x), crate::sign(self.y))
@@ -143,6 +370,22 @@ impl Vec2 {
         self.max(min).min(max)
     }
 
+    /// The cosine of the angle between two vectors, from 1.0 when they point the same way to
+    /// -1.0 when they point in opposite directions.
+    pub fn cos_angle(&self, other: Self) -> f32 {
+        self.norm().dot(other.norm())
+    }
+
+    /// Check if the vector points within `half_angle` of `direction`. Zero-length vectors
+    /// are never inside the cone.
+    pub fn within_cone(&self, direction: Self, half_angle: impl Into<Radians>) -> bool {
+        if self.sqr_len() == 0.0 || direction.sqr_len() == 0.0 {
+            return false;
+        }
+        let Radians(a) = half_angle.into();
+        self.cos_angle(direction) >= a.cos()
+    }
+
     /// Return the dot product of two vectors.
     pub fn dot(&self, other: Self) -> f32 {
         self.x * other.x + self.y * other.y
@@ -153,11 +396,22 @@ impl Vec2 {
         self.x * other.y - self.y * other.x
     }
 
+    /// Return the perpendicular dot product of two vectors, the same value as `cross`.
+    pub fn perp_dot(&self, other: Self) -> f32 {
+        self.cross(other)
+    }
+
     /// Given an origin and normalized axis, project the vector into a point along that axis.
     pub fn project(&self, origin: Self, axis: Self) -> Self {
         origin + axis * self.dot(axis)
     }
 
+    /// Remove the part of the vector pointing along the normalized `normal`, leaving only
+    /// the part that runs along the surface it belongs to.
+    pub fn project_on_normal(&self, normal: Self) -> Self {
+        *self - normal * self.dot(normal)
+    }
+
     /// Get the square distance between two vectors.
     pub fn sqr_dist(&self, other: Self) -> f32 {
         let x = self.x - other.x;
@@ -170,6 +424,32 @@ impl Vec2 {
         self.sqr_dist(other).sqrt()
     }
 
+    /// Get the manhattan distance between two vectors.
+    pub fn manhattan_dist(&self, other: Self) -> f32 {
+        (*self - other).manhattan_len()
+    }
+
+    /// Get the chebyshev distance between two vectors.
+    pub fn chebyshev_dist(&self, other: Self) -> f32 {
+        (*self - other).chebyshev_len()
+    }
+
+    /// Get the closest point to this one on the line segment `a -> b`.
+    pub fn closest_point_on_segment(&self, a: Self, b: Self) -> Self {
+        let ab = b - a;
+        let len = ab.sqr_len();
+        if len == 0.0 {
+            return a;
+        }
+        let t = ((*self - a).dot(ab) / len).clamp(0.0, 1.0);
+        a + ab * t
+    }
+
+    /// Get the euclidean distance from this point to the line segment `a -> b`.
+    pub fn dist_to_segment(&self, a: Self, b: Self) -> f32 {
+        self.dist(self.closest_point_on_segment(a, b))
+    }
+
     /// Linear interpolation between two vectors by a factor `t`.
     /// For example, `t = 0.5` would return the midpoint between the two vectors.
     pub fn lerp(&self, other: Self, t: f32) -> Self {
@@ -179,6 +459,40 @@ impl Vec2 {
         )
     }
 
+    /// Linear interpolation between two vectors by a factor `t`, clamped in range (0.0 - 1.0)
+    /// so the result never extrapolates past either vector.
+    pub fn lerp_clamped(&self, other: Self, t: f32) -> Self {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Normalized linear interpolation between two normalized vectors by a factor `t`. This is
+    /// cheaper than [slerp()](#method.slerp) and follows the same arc, but cuts across the chord
+    /// so it moves faster near the middle than at the ends. Anti-parallel vectors at their
+    /// midpoint return zero.
+    pub fn nlerp(&self, other: Self, t: f32) -> Self {
+        let v = self.lerp(other, t);
+        match v.sqr_len() > 0.0 {
+            true => v.norm(),
+            false => Self::ZERO,
+        }
+    }
+
+    /// Spherical interpolation between two normalized vectors by a factor `t`.
+    /// Unlike `lerp` and [nlerp()](#method.nlerp), this moves along the arc between them at a
+    /// constant angular speed, at the cost of a few trigonometric calls.
+    pub fn slerp(&self, other: Self, t: f32) -> Self {
+        let dot = self.dot(other).clamp(-1.0, 1.0);
+        if dot > 0.9995 {
+            return self.lerp(other, t).norm();
+        }
+        let theta = dot.acos() * t;
+        let rel = match dot < -0.9995 {
+            true => self.turn_right(),
+            false => (other - *self * dot).norm(),
+        };
+        *self * theta.cos() + rel * theta.sin()
+    }
+
     /// Quadratic bezier interpolation by a factor `t`, using `b` as the anchor point.
     pub fn bezier3(&self, b: Self, c: Self, t: f32) -> Self {
         vec2(
@@ -203,6 +517,27 @@ impl Vec2 {
         )
     }
 
+    /// Catmull-Rom interpolation along a path of any length, where `t` in range (0.0 - 1.0)
+    /// spans the whole path. The curve passes through every point, with the first and last
+    /// points repeated to serve as the outer anchors.
+    pub fn catmull_rom_spline(points: &[Self], t: f32) -> Self {
+        match points.len() {
+            0 => Self::ZERO,
+            1 => points[0],
+            n => {
+                let segments = n - 1;
+                let f = t.clamp(0.0, 1.0) * segments as f32;
+                let i = (f as usize).min(segments - 1);
+                points[i.saturating_sub(1)].catmull_rom(
+                    points[i],
+                    points[i + 1],
+                    points[(i + 2).min(segments)],
+                    f - i as f32,
+                )
+            }
+        }
+    }
+
     /// Hermite interpolation by a factor `t` using the provided tangents.
     pub fn hermite(&self, self_tangent: Self, other: Self, other_tangent: Self, t: f32) -> Self {
         vec2(
@@ -217,15 +552,47 @@ impl Vec2 {
         vec2(self.x - normal.x * val, self.y - normal.y * val)
     }
 
+    /// Refract a normalized direction through a surface with the provided normal, where `eta`
+    /// is the ratio of the refractive indices on either side. Returns `None` on total
+    /// internal reflection.
+    pub fn refract(&self, normal: Self, eta: f32) -> Option<Self> {
+        let cos_i = normal.dot(*self);
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        match k < 0.0 {
+            true => None,
+            false => Some(*self * eta - normal * (eta * cos_i + k.sqrt())),
+        }
+    }
+
     /// Smooth-step interpolation between vectors by factor `t`.
     pub fn smooth_step(&self, target: Self, t: f32) -> Self {
         self.lerp(target, crate::smooth_step(t))
     }
+
+    /// Smoother-step interpolation between vectors by factor `t`.
+    pub fn smoother_step(&self, target: Self, t: f32) -> Self {
+        self.lerp(target, crate::smoother_step(t))
+    }
+
+    /// Move towards `target` with frame-rate independent exponential smoothing.
+    /// See [damp()](fn.damp.html).
+    pub fn damp(&self, target: Self, lambda: f32, dt: f32) -> Self {
+        self.lerp(target, 1.0 - (-lambda * dt).exp())
+    }
+
+    /// Move towards `target` with a critically damped spring, springing each component with
+    /// its own part of `velocity`. See [spring()](fn.spring.html).
+    pub fn spring(&self, target: Self, velocity: &mut Self, smooth_time: f32, dt: f32) -> Self {
+        vec2(
+            crate::spring(self.x, target.x, &mut velocity.x, smooth_time, dt),
+            crate::spring(self.y, target.y, &mut velocity.y, smooth_time, dt),
+        )
+    }
 }
 
 impl AsRef<[f32]> for Vec2 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const f32, 2) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 2) }
     }
 }
 
@@ -395,4 +762,338 @@ impl RemAssign<f32> for Vec2 {
     fn rem_assign(&mut self, rhs: f32) {
         *self = self.rem(rhs);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<f32>> for Vec2 {
+    fn from(val: mint::Vector2<f32>) -> Self {
+        vec2(val.x, val.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec2> for mint::Vector2<f32> {
+    fn from(val: Vec2) -> Self {
+        mint::Vector2 { x: val.x, y: val.y }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for Vec2 {
+    fn from(val: glam::Vec2) -> Self {
+        vec2(val.x, val.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec2> for glam::Vec2 {
+    fn from(val: Vec2) -> Self {
+        glam::Vec2::new(val.x, val.y)
+    }
+}
+
+/// Samples each component in the range `[0, 1)`.
+#[cfg(feature = "rand")]
+impl Distribution<Vec2> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        vec2(rng.gen(), rng.gen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{int2, Degrees, PI};
+
+    #[test]
+    fn slerp_midpoint() {
+        let mid = Vec2::RIGHT.slerp(Vec2::UP, 0.5);
+        assert!(crate::approx_f32(mid.len(), 1.0));
+        assert!(crate::approx_f32(mid.angle(), -PI / 4.0));
+        let opposite = Vec2::RIGHT.slerp(Vec2::LEFT, 0.5);
+        assert!(crate::approx_f32(opposite.len(), 1.0));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast_slice() {
+        let points = [vec2(1.0, 2.0), vec2(3.0, 4.0), vec2(5.0, 6.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(bytes.len(), points.len() * 8);
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    /// A tiny xorshift generator, so the tests don't need `rand`'s std features.
+    #[cfg(feature = "rand")]
+    struct XorShift(u64);
+
+    #[cfg(feature = "rand")]
+    impl rand::RngCore for XorShift {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_unit_mean_is_near_zero() {
+        let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+        let n = 10_000;
+        let mut sum = Vec2::ZERO;
+        for _ in 0..n {
+            let p = Vec2::random_unit(&mut rng);
+            assert!(crate::approx_f32(p.len(), 1.0));
+            sum += p;
+        }
+        assert!((sum / n as f32).len() < 0.05);
+
+        let c: Vec2 = rng.gen();
+        assert!((0.0..1.0).contains(&c.x) && (0.0..1.0).contains(&c.y));
+    }
+
+    #[test]
+    fn recip_and_elements() {
+        assert_eq!(vec2(2.0, 4.0).recip(), vec2(0.5, 0.25));
+        assert_eq!(vec2(2.0, -4.0).min_element(), -4.0);
+        assert_eq!(vec2(2.0, -4.0).max_element(), 2.0);
+    }
+
+    #[test]
+    fn catmull_rom_spline_passes_through_points() {
+        let path = [
+            vec2(0.0, 0.0),
+            vec2(1.0, 2.0),
+            vec2(3.0, 2.0),
+            vec2(4.0, 0.0),
+        ];
+        for (i, p) in path.iter().enumerate() {
+            let t = i as f32 / 3.0;
+            assert!(Vec2::catmull_rom_spline(&path, t).approx_eq(p));
+        }
+        let mid = Vec2::catmull_rom_spline(&path, 0.5);
+        assert!(crate::approx_f32(mid.x, 2.0));
+        assert!(mid.y > 2.0);
+        assert_eq!(Vec2::catmull_rom_spline(&path[..1], 0.5), path[0]);
+    }
+
+    #[test]
+    fn const_table() {
+        const TABLE: [Vec2; 2] = [vec2(0.0, 0.0), vec2(1.0, 1.0)];
+        assert_eq!(TABLE[1], Vec2::ONE);
+    }
+
+    #[test]
+    fn lerp_clamped_stays_in_range() {
+        let (a, b) = (Vec2::ZERO, vec2(2.0, 4.0));
+        assert_eq!(a.lerp(b, 1.5), vec2(3.0, 6.0));
+        assert_eq!(a.lerp_clamped(b, 1.5), b);
+        assert_eq!(a.lerp_clamped(b, -1.0), a);
+        assert_eq!(a.smoother_step(b, 0.5), vec2(1.0, 2.0));
+    }
+
+    #[test]
+    fn approx_eq_vs_exact() {
+        // `0.1 + 0.2` happens to round to `0.3` exactly in f32, so use a pair that doesn't
+        let sum = vec2(0.3, 0.6) + vec2(0.6, 0.3);
+        assert_ne!(sum, vec2(0.9, 0.9));
+        assert!(sum.approx_eq(&vec2(0.9, 0.9)));
+        assert!(!sum.approx_eq(&vec2(0.9, 1.0)));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        assert_eq!(vec2(0.7, 1.2).snap(0.5), vec2(0.5, 1.0));
+        assert_eq!(vec2(-0.8, 1.2).snap(vec2(0.5, 0.0)), vec2(-1.0, 1.2));
+    }
+
+    #[test]
+    fn closest_point_on_segment() {
+        let (a, b) = (vec2(0.0, 0.0), vec2(10.0, 0.0));
+        assert_eq!(vec2(15.0, 3.0).closest_point_on_segment(a, b), b);
+        assert_eq!(vec2(-2.0, -1.0).closest_point_on_segment(a, b), a);
+        assert_eq!(
+            vec2(4.0, 3.0).closest_point_on_segment(a, b),
+            vec2(4.0, 0.0)
+        );
+        assert!(crate::approx_f32(vec2(4.0, 3.0).dist_to_segment(a, b), 3.0));
+        assert!(crate::approx_f32(
+            vec2(13.0, 4.0).dist_to_segment(a, b),
+            5.0
+        ));
+        assert_eq!(vec2(4.0, 3.0).closest_point_on_segment(b, b), b);
+    }
+
+    #[test]
+    fn to_int2_rounding_modes() {
+        let v = vec2(-0.5, 1.9);
+        assert_eq!(v.floor_to_int2(), int2(-1, 1));
+        assert_eq!(v.ceil_to_int2(), int2(0, 2));
+        // `f32::round` rounds half away from zero
+        assert_eq!(v.round_to_int2(), int2(-1, 2));
+    }
+
+    #[test]
+    fn length_metrics() {
+        let v = vec2(3.0, -4.0);
+        assert_eq!(v.len(), 5.0);
+        assert_eq!(v.manhattan_len(), 7.0);
+        assert_eq!(v.chebyshev_len(), 4.0);
+        let o = vec2(1.0, 1.0);
+        assert_eq!((o + v).manhattan_dist(o), 7.0);
+        assert_eq!(o.chebyshev_dist(o + v), 4.0);
+    }
+
+    #[test]
+    fn rotate_towards_snaps_to_target() {
+        let v = vec2(2.0, 0.0);
+        let target = vec2(0.0, 5.0);
+        assert_eq!(v.rotate_towards(target, PI), vec2(0.0, 2.0));
+        assert_eq!(v.rotate_towards(target, Degrees(120.0)), vec2(0.0, 2.0));
+        let step = v.rotate_towards(target, 0.5);
+        assert!(crate::approx_f32(step.len(), 2.0));
+        assert!(crate::approx_f32(v.angle_to(step), 0.5));
+        let back = v.rotate_towards(vec2(0.0, -5.0), 0.5);
+        assert!(crate::approx_f32(v.angle_to(back), -0.5));
+    }
+
+    #[test]
+    fn nan_and_finite_checks() {
+        assert!(vec2(f32::NAN, 0.0).is_nan());
+        assert!(!vec2(f32::NAN, 0.0).is_finite());
+        assert!(vec2(1.0, 2.0).is_finite() && !vec2(1.0, 2.0).is_nan());
+    }
+
+    #[test]
+    fn within_cone_ahead_and_behind() {
+        let facing = vec2(1.0, 0.0);
+        assert!(vec2(10.0, 0.0).within_cone(facing, Degrees(45.0)));
+        assert!(vec2(10.0, 9.0).within_cone(facing, Degrees(45.0)));
+        assert!(!vec2(-10.0, 0.0).within_cone(facing, Degrees(45.0)));
+        assert!(!Vec2::ZERO.within_cone(facing, Degrees(45.0)));
+        assert!(crate::approx_f32(
+            vec2(3.0, 3.0).cos_angle(facing),
+            0.5f32.sqrt()
+        ));
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        assert_eq!(vec2(4.0, 9.0).map(f32::sqrt), vec2(2.0, 3.0));
+        assert_eq!(
+            vec2(1.0, 5.0).zip_with(vec2(3.0, 2.0), f32::max),
+            vec2(3.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn refract_identity_and_total_internal_reflection() {
+        let n = vec2(0.0, 1.0);
+        let i = vec2(1.0, -2.0).norm();
+        assert!(i.refract(n, 1.0).unwrap().approx_eq(&i));
+        let grazing = vec2(1.0, -0.1).norm();
+        assert_eq!(grazing.refract(n, 1.5), None);
+        let bent = i.refract(n, 1.0 / 1.5).unwrap();
+        assert!(crate::approx_f32(bent.len(), 1.0));
+        assert!(bent.x.abs() < i.x.abs());
+    }
+
+    #[test]
+    fn nearest_cardinal_and_direction_8() {
+        let deg = |d: f32| Vec2::polar(d.to_radians()) * 3.0;
+        assert_eq!(deg(30.0).nearest_cardinal(), Vec2::RIGHT);
+        assert_eq!(deg(100.0).nearest_cardinal(), Vec2::DOWN);
+        assert_eq!(deg(170.0).nearest_cardinal(), Vec2::LEFT);
+        assert_eq!(deg(-80.0).nearest_cardinal(), Vec2::UP);
+        assert_eq!(Vec2::ZERO.nearest_cardinal(), Vec2::ZERO);
+
+        assert_eq!(Vec2::direction_8(0), Vec2::RIGHT);
+        assert_eq!(Vec2::direction_8(2), Vec2::DOWN);
+        assert_eq!(Vec2::direction_8(4), Vec2::LEFT);
+        assert_eq!(Vec2::direction_8(6), Vec2::UP);
+        assert_eq!(Vec2::direction_8(9), Vec2::direction_8(1));
+        for i in 0..8 {
+            let d = Vec2::direction_8(i);
+            assert!((d.len() - 1.0).abs() < 1e-6);
+            assert!(d.max_abs_diff(deg(i as f32 * 45.0) / 3.0) < 1e-6);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn signed_zero_hashes_equally() {
+        use std::collections::hash_map::DefaultHasher;
+        let hash = |v: Vec2| {
+            let mut h = DefaultHasher::new();
+            v.hash(&mut h);
+            h.finish()
+        };
+        let (a, b) = (vec2(0.0, 0.0), vec2(-0.0, -0.0));
+        assert_eq!(a, b);
+        assert_eq!(hash(a), hash(b));
+
+        let nan = f32::from_bits(f32::NAN.to_bits() ^ 1);
+        assert_eq!(crate::hash_f32(nan), crate::hash_f32(f32::NAN));
+        assert_eq!(crate::hash_f32(-f32::NAN), crate::hash_f32(f32::NAN));
+        assert_ne!(crate::hash_f32(1.0), crate::hash_f32(-1.0));
+    }
+
+    #[test]
+    fn project_on_normal_slides_along_slope() {
+        let normal = vec2(1.0, -1.0).norm();
+        let slide = vec2(0.0, 2.0).project_on_normal(normal);
+        assert!(slide.dot(normal).abs() < 1e-6);
+        assert!(slide.max_abs_diff(vec2(1.0, 1.0)) < 1e-6);
+    }
+
+    #[test]
+    fn nlerp_vs_slerp_angles() {
+        use core::f32::consts::FRAC_PI_4;
+        let (a, b) = (Vec2::RIGHT, Vec2::UP);
+        let mid_n = a.nlerp(b, 0.5);
+        let mid_s = a.slerp(b, 0.5);
+        assert!((mid_n.angle() + FRAC_PI_4).abs() < 1e-5);
+        assert!((mid_s.angle() + FRAC_PI_4).abs() < 1e-5);
+
+        // slerp keeps a constant angular speed, nlerp lags behind near the ends
+        let quarter_s = a.slerp(b, 0.25).angle();
+        let quarter_n = a.nlerp(b, 0.25).angle();
+        assert!((quarter_s + FRAC_PI_4 * 0.5).abs() < 1e-5);
+        assert!((quarter_n - quarter_s).abs() > 0.05);
+        assert!((a.nlerp(b, 0.25).len() - 1.0).abs() < 1e-5);
+
+        let flip = a.slerp(Vec2::LEFT, 0.5);
+        assert!((flip.len() - 1.0).abs() < 1e-5);
+        assert!(flip.dot(a).abs() < 1e-5);
+        assert_eq!(a.nlerp(Vec2::LEFT, 0.5), Vec2::ZERO);
+        assert!(a.slerp(vec2(1.0, 1e-4).norm(), 0.5).is_finite());
+    }
+
+    #[test]
+    fn extents_and_centroid_of_points() {
+        let pts = [vec2(1.0, -2.0), vec2(-3.0, 4.0), vec2(5.0, 1.0)];
+        assert_eq!(Vec2::min_components(&pts), vec2(-3.0, -2.0));
+        assert_eq!(Vec2::max_components(&pts), vec2(5.0, 4.0));
+        assert_eq!(Vec2::centroid(&pts), vec2(1.0, 1.0));
+        assert_eq!(Vec2::centroid(&[]), Vec2::ZERO);
+    }
+}
@@ -1,13 +1,25 @@
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Neg, Sub, Rem, RemAssign, AddAssign, SubAssign, MulAssign, DivAssign, Index};
-use crate::{Vec2, Vec4};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Radians, Vec2, Vec4};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 /// A three-dimensional floating point vector.
 #[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Vec3 {
     pub x: f32,
@@ -16,10 +28,24 @@ pub struct Vec3 {
 }
 
 /// Easy constructor.
-pub fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
+pub const fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
     Vec3 { x, y, z }
 }
 
+/// Check if the four points lie in one plane, within `eps` of the
+/// [triple product](struct.Vec3.html#method.triple_product) of the edges from `a`.
+pub fn are_coplanar(a: Vec3, b: Vec3, c: Vec3, d: Vec3, eps: f32) -> bool {
+    (b - a).triple_product(c - a, d - a).abs() <= eps
+}
+
+/// Rotate `v` by `angle` around the normalized `axis` using
+/// [Rodrigues' rotation formula](https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula),
+/// in the same direction as [Mat4x4::rotation_axis()](struct.Mat4x4.html#method.rotation_axis).
+pub fn rotate_around_axis(v: Vec3, axis: Vec3, angle: impl Into<Radians>) -> Vec3 {
+    let (s, c) = angle.into().sin_cos();
+    v * c + axis.cross(v) * s + axis * (axis.dot(v) * (1.0 - c))
+}
+
 #[allow(clippy::len_without_is_empty)]
 impl Vec3 {
     /// (0.0, 0.0, 0.0)
@@ -72,20 +98,108 @@ impl Vec3 {
     };
 
     /// Create a new vector.
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         vec3(x, y, z)
     }
 
+    /// The vector's components as an array.
+    pub fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Create a new vector from an array of components.
+    pub fn from_array(a: [f32; 3]) -> Self {
+        vec3(a[0], a[1], a[2])
+    }
+
+    /// Read the vector from the first 3 floats of `s`. Panics if `s` is too short.
+    pub fn from_slice(s: &[f32]) -> Self {
+        vec3(s[0], s[1], s[2])
+    }
+
+    /// Read the vector from the first 3 floats of `s`, or `None` if `s` is too short.
+    pub fn try_from_slice(s: &[f32]) -> Option<Self> {
+        match s.len() >= 3 {
+            true => Some(Self::from_slice(s)),
+            false => None,
+        }
+    }
+
+    /// Write the vector into the first 3 floats of `s`. Panics if `s` is too short.
+    pub fn write_to_slice(&self, s: &mut [f32]) {
+        s[..3].copy_from_slice(&self.to_array());
+    }
+
     /// Extend this vector with a w-axis.
     pub fn extend(&self, w: f32) -> Vec4 {
         crate::vec4(self.x, self.y, self.z, w)
     }
 
+    /// The x and y axes of the vector.
+    pub fn xy(&self) -> Vec2 {
+        crate::vec2(self.x, self.y)
+    }
+
+    /// The x and z axes of the vector.
+    pub fn xz(&self) -> Vec2 {
+        crate::vec2(self.x, self.z)
+    }
+
+    /// The y and z axes of the vector.
+    pub fn yz(&self) -> Vec2 {
+        crate::vec2(self.y, self.z)
+    }
+
     /// Compose a new vector from a `Vec2` and the provided `z` axis.
     pub fn from(val: Vec2, z: f32) -> Self {
         vec3(val.x, val.y, z)
     }
 
+    /// Create a vector from spherical coordinates, with y as the up axis. The `azimuth` is
+    /// the angle around the y-axis, starting at `Vec3::RIGHT` and turning towards
+    /// `Vec3::FORWARD`, and the `inclination` is the angle up from the xz-plane.
+    pub fn from_spherical(
+        radius: f32,
+        azimuth: impl Into<Radians>,
+        inclination: impl Into<Radians>,
+    ) -> Self {
+        let Radians(az) = azimuth.into();
+        let Radians(inc) = inclination.into();
+        let flat = radius * inc.cos();
+        vec3(flat * az.cos(), radius * inc.sin(), flat * az.sin())
+    }
+
+    /// Convert the vector to spherical coordinates `(radius, azimuth, inclination)`, the
+    /// inverse of [from_spherical()](#method.from_spherical). The azimuth is in range (-π - π)
+    /// and the inclination in range (-π/2 - π/2).
+    pub fn to_spherical(&self) -> (f32, Radians, Radians) {
+        let flat = (self.x * self.x + self.z * self.z).sqrt();
+        (
+            self.len(),
+            Radians(self.z.atan2(self.x)),
+            Radians(self.y.atan2(flat)),
+        )
+    }
+
+    /// Create a random point on the unit sphere, uniformly distributed over its surface.
+    ///
+    /// Points are sampled inside the unit cube and rejected until one falls inside the
+    /// sphere, which is then normalized.
+    #[cfg(feature = "rand")]
+    pub fn random_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let p = vec3(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            let sqr_len = p.sqr_len();
+            if sqr_len > 1e-6 && sqr_len <= 1.0 {
+                return p / sqr_len.sqrt();
+            }
+        }
+    }
+
     /// The length of the vector, squared.
     pub fn sqr_len(&self) -> f32 {
         self.x * self.x + self.y * self.y + self.z * self.z
@@ -96,12 +210,57 @@ impl Vec3 {
         self.sqr_len().sqrt()
     }
 
+    /// The manhattan length of the vector, which is the sum of its absolute components.
+    pub fn manhattan_len(&self) -> f32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// The chebyshev length of the vector, which is its largest absolute component.
+    pub fn chebyshev_len(&self) -> f32 {
+        self.abs().max_element()
+    }
+
+    /// Check if all of the vector's components are finite, neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Check if any of the vector's components are NaN.
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Check if two vectors are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
+    /// The absolute difference between each of the vectors' components.
+    pub fn abs_diff(&self, other: Self) -> Self {
+        (*self - other).abs()
+    }
+
+    /// The largest absolute difference between any of the vectors' components.
+    pub fn max_abs_diff(&self, other: Self) -> f32 {
+        self.abs_diff(other).max_element()
+    }
+
     /// Normalize the vector.
     pub fn norm(&self) -> Self {
         let len = self.len();
         vec3(self.x / len, self.y / len, self.z / len)
     }
 
+    /// Normalize the vector, also returning its length from before. The zero vector returns
+    /// `(ZERO, 0.0)`.
+    pub fn norm_and_len(&self) -> (Self, f32) {
+        let len = self.len();
+        match len > 0.0 {
+            true => (vec3(self.x / len, self.y / len, self.z / len), len),
+            false => (Self::ZERO, 0.0),
+        }
+    }
+
     /// Zero the vector's y and z axes.
     pub fn only_x(&self) -> Self {
         vec3(self.x, 0.0, 0.0)
@@ -117,6 +276,16 @@ impl Vec3 {
         vec3(0.0, 0.0, self.z)
     }
 
+    /// Apply a function to each of the vector's components.
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        vec3(f(self.x), f(self.y), f(self.z))
+    }
+
+    /// Combine the components of two vectors pairwise with a function.
+    pub fn zip_with(&self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        vec3(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+
     /// Absolute the vector's components.
     pub fn abs(&self) -> Self {
         vec3(self.x.abs(), self.y.abs(), self.z.abs())
@@ -137,6 +306,50 @@ impl Vec3 {
         vec3(self.x.round(), self.y.round(), self.z.round())
     }
 
+    /// Round the vector's components down, converting them to integers.
+    pub fn floor_to_int3(&self) -> crate::Int3 {
+        crate::int3(
+            self.x.floor() as i32,
+            self.y.floor() as i32,
+            self.z.floor() as i32,
+        )
+    }
+
+    /// Round the vector's components up, converting them to integers.
+    pub fn ceil_to_int3(&self) -> crate::Int3 {
+        crate::int3(
+            self.x.ceil() as i32,
+            self.y.ceil() as i32,
+            self.z.ceil() as i32,
+        )
+    }
+
+    /// Round the vector's components to the nearest whole number (half-way cases away from zero), converting them to integers.
+    pub fn round_to_int3(&self) -> crate::Int3 {
+        crate::int3(
+            self.x.round() as i32,
+            self.y.round() as i32,
+            self.z.round() as i32,
+        )
+    }
+
+    /// Round the vector's components to the nearest multiple of `step`.
+    /// Axes with a step of zero are left unchanged.
+    pub fn snap(&self, step: impl Into<Vec3>) -> Self {
+        fn snap(v: f32, step: f32) -> f32 {
+            match step == 0.0 {
+                true => v,
+                false => (v / step).round() * step,
+            }
+        }
+        let step = step.into();
+        vec3(
+            snap(self.x, step.x),
+            snap(self.y, step.y),
+            snap(self.z, step.z),
+        )
+    }
+
     /// Return the minimum of the vector's components.
     pub fn min(&self, other: Self) -> Self {
         vec3(
@@ -155,6 +368,47 @@ impl Vec3 {
         )
     }
 
+    /// Return the smallest of the vector's components.
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Return the largest of the vector's components.
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// The smallest value of each component across all of the vectors, or `ZERO` if there
+    /// are none.
+    pub fn min_components(vs: &[Self]) -> Self {
+        match vs.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |a, b| a.min(*b)),
+            None => Self::ZERO,
+        }
+    }
+
+    /// The largest value of each component across all of the vectors, or `ZERO` if there
+    /// are none.
+    pub fn max_components(vs: &[Self]) -> Self {
+        match vs.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |a, b| a.max(*b)),
+            None => Self::ZERO,
+        }
+    }
+
+    /// The average of all of the vectors, or `ZERO` if there are none.
+    pub fn centroid(vs: &[Self]) -> Self {
+        match vs.is_empty() {
+            true => Self::ZERO,
+            false => vs.iter().fold(Self::ZERO, |a, b| a + *b) / vs.len() as f32,
+        }
+    }
+
+    /// Return the reciprocal of the vector's components.
+    pub fn recip(&self) -> Self {
+        vec3(1.0 / self.x, 1.0 / self.y, 1.0 / self.z)
+    }
+
     /// Return the sign of the vector's components.
     pub fn sign(&self) -> Self {
         vec3(
@@ -169,11 +423,68 @@ impl Vec3 {
         self.max(min).min(max)
     }
 
+    /// The unsigned angle between two vectors, in range (0 - π).
+    pub fn angle_between(&self, other: Self) -> Radians {
+        Radians(self.cross(other).len().atan2(self.dot(other)))
+    }
+
+    /// The angle between two vectors, positive if rotating from this one to `other` turns
+    /// counter-clockwise around `axis` and negative otherwise.
+    pub fn signed_angle_around(&self, other: Self, axis: Self) -> Radians {
+        let Radians(a) = self.angle_between(other);
+        match self.cross(other).dot(axis) < 0.0 {
+            true => Radians(-a),
+            false => Radians(a),
+        }
+    }
+
+    /// The cosine of the angle between two vectors, from 1.0 when they point the same way to
+    /// -1.0 when they point in opposite directions.
+    pub fn cos_angle(&self, other: Self) -> f32 {
+        self.norm().dot(other.norm())
+    }
+
+    /// Check if the vector points within `half_angle` of `direction`. Zero-length vectors
+    /// are never inside the cone.
+    pub fn within_cone(&self, direction: Self, half_angle: impl Into<Radians>) -> bool {
+        if self.sqr_len() == 0.0 || direction.sqr_len() == 0.0 {
+            return false;
+        }
+        let Radians(a) = half_angle.into();
+        self.cos_angle(direction) >= a.cos()
+    }
+
     /// Return the dot product of two vectors.
     pub fn dot(&self, other: Self) -> f32 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
 
+    /// Project the vector onto `onto`, returning the part of it that points along `onto`.
+    pub fn project(&self, onto: Self) -> Self {
+        let sqr_len = onto.sqr_len();
+        match sqr_len > 0.0 {
+            true => onto * (self.dot(onto) / sqr_len),
+            false => Self::ZERO,
+        }
+    }
+
+    /// Reject the vector from `onto`, returning the part of it perpendicular to `onto`.
+    pub fn reject(&self, onto: Self) -> Self {
+        *self - self.project(onto)
+    }
+
+    /// Remove the part of the vector pointing along the plane's normalized `normal`, leaving
+    /// only the part that runs along the plane.
+    pub fn project_on_plane(&self, normal: Self) -> Self {
+        *self - normal * self.dot(normal)
+    }
+
+    /// Return the scalar triple product `self · (b × c)`, which is the signed volume of the
+    /// parallelepiped the three vectors span.
+    pub fn triple_product(&self, b: Self, c: Self) -> f32 {
+        self.dot(b.cross(c))
+    }
+
     /// Return the cross product of two vectors.
     pub fn cross(&self, other: Self) -> Self {
         vec3(
@@ -183,6 +494,25 @@ impl Vec3 {
         )
     }
 
+    /// Return two unit vectors perpendicular to this one and to each other, which together
+    /// form an orthonormal basis. The vector is assumed to be normalized.
+    ///
+    /// Uses the branchless method from [Duff et al. 2017](https://graphics.pixar.com/library/OrthonormalB/paper.pdf).
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        let sign = self.z.signum();
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            vec3(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            vec3(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Return `other` with any component along this (normalized) vector removed, normalized.
+    pub fn orthonormalize(&self, other: Self) -> Self {
+        (other - *self * self.dot(other)).norm()
+    }
+
     /// Get the square distance between two vectors.
     pub fn sqr_dist(&self, other: Self) -> f32 {
         let x = self.x - other.x;
@@ -196,6 +526,16 @@ impl Vec3 {
         self.sqr_dist(other).sqrt()
     }
 
+    /// Get the manhattan distance between two vectors.
+    pub fn manhattan_dist(&self, other: Self) -> f32 {
+        (*self - other).manhattan_len()
+    }
+
+    /// Get the chebyshev distance between two vectors.
+    pub fn chebyshev_dist(&self, other: Self) -> f32 {
+        (*self - other).chebyshev_len()
+    }
+
     /// Linear interpolation between two vectors by a factor `t`.
     /// For example, `t = 0.5` would return the midpoint between the two vectors.
     pub fn lerp(&self, other: Self, t: f32) -> Self {
@@ -206,6 +546,31 @@ impl Vec3 {
         )
     }
 
+    /// Linear interpolation between two vectors by a factor `t`, clamped in range (0.0 - 1.0)
+    /// so the result never extrapolates past either vector.
+    pub fn lerp_clamped(&self, other: Self, t: f32) -> Self {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
+    /// Spherical interpolation between two normalized vectors by a factor `t`.
+    /// Unlike `lerp`, this moves along the arc between them at a constant angular speed.
+    pub fn slerp(&self, other: Self, t: f32) -> Self {
+        let dot = self.dot(other).clamp(-1.0, 1.0);
+        if dot > 0.9995 {
+            return self.lerp(other, t).norm();
+        }
+        let theta = dot.acos() * t;
+        let rel = other - *self * dot;
+        let rel = match rel.sqr_len() > 1e-10 {
+            true => rel.norm(),
+            false => match self.x.abs() < 0.9 {
+                true => self.cross(Self::RIGHT).norm(),
+                false => self.cross(Self::UP).norm(),
+            },
+        };
+        *self * theta.cos() + rel * theta.sin()
+    }
+
     /// Quadratic bezier interpolation by a factor `t`, using `b` as the anchor point.
     pub fn bezier3(&self, b: Self, c: Self, t: f32) -> Self {
         vec3(
@@ -233,6 +598,27 @@ impl Vec3 {
         )
     }
 
+    /// Catmull-Rom interpolation along a path of any length, where `t` in range (0.0 - 1.0)
+    /// spans the whole path. The curve passes through every point, with the first and last
+    /// points repeated to serve as the outer anchors.
+    pub fn catmull_rom_spline(points: &[Self], t: f32) -> Self {
+        match points.len() {
+            0 => Self::ZERO,
+            1 => points[0],
+            n => {
+                let segments = n - 1;
+                let f = t.clamp(0.0, 1.0) * segments as f32;
+                let i = (f as usize).min(segments - 1);
+                points[i.saturating_sub(1)].catmull_rom(
+                    points[i],
+                    points[i + 1],
+                    points[(i + 2).min(segments)],
+                    f - i as f32,
+                )
+            }
+        }
+    }
+
     /// Hermite interpolation by a factor `t` using the provided tangents.
     pub fn hermite(&self, self_tangent: Self, other: Self, other_tangent: Self, t: f32) -> Self {
         vec3(
@@ -242,15 +628,48 @@ impl Vec3 {
         )
     }
 
+    /// Refract a normalized direction through a surface with the provided normal, where `eta`
+    /// is the ratio of the refractive indices on either side. Returns `None` on total
+    /// internal reflection.
+    pub fn refract(&self, normal: Self, eta: f32) -> Option<Self> {
+        let cos_i = normal.dot(*self);
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        match k < 0.0 {
+            true => None,
+            false => Some(*self * eta - normal * (eta * cos_i + k.sqrt())),
+        }
+    }
+
     /// Smooth-step interpolation between vectors by factor `t`.
     pub fn smooth_step(&self, target: Self, t: f32) -> Self {
         self.lerp(target, crate::smooth_step(t))
     }
+
+    /// Smoother-step interpolation between vectors by factor `t`.
+    pub fn smoother_step(&self, target: Self, t: f32) -> Self {
+        self.lerp(target, crate::smoother_step(t))
+    }
+
+    /// Move towards `target` with frame-rate independent exponential smoothing.
+    /// See [damp()](fn.damp.html).
+    pub fn damp(&self, target: Self, lambda: f32, dt: f32) -> Self {
+        self.lerp(target, 1.0 - (-lambda * dt).exp())
+    }
+
+    /// Move towards `target` with a critically damped spring, springing each component with
+    /// its own part of `velocity`. See [spring()](fn.spring.html).
+    pub fn spring(&self, target: Self, velocity: &mut Self, smooth_time: f32, dt: f32) -> Self {
+        vec3(
+            crate::spring(self.x, target.x, &mut velocity.x, smooth_time, dt),
+            crate::spring(self.y, target.y, &mut velocity.y, smooth_time, dt),
+            crate::spring(self.z, target.z, &mut velocity.z, smooth_time, dt),
+        )
+    }
 }
 
 impl AsRef<[f32]> for Vec3 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const f32, 3) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 3) }
     }
 }
 
@@ -433,4 +852,323 @@ impl RemAssign<f32> for Vec3 {
     fn rem_assign(&mut self, rhs: f32) {
         *self = self.rem(rhs);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f32>> for Vec3 {
+    fn from(val: mint::Vector3<f32>) -> Self {
+        vec3(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec3> for mint::Vector3<f32> {
+    fn from(val: Vec3) -> Self {
+        mint::Vector3 {
+            x: val.x,
+            y: val.y,
+            z: val.z,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Vec3 {
+    fn from(val: glam::Vec3) -> Self {
+        vec3(val.x, val.y, val.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::Vec3 {
+    fn from(val: Vec3) -> Self {
+        glam::Vec3::new(val.x, val.y, val.z)
+    }
+}
+
+/// Samples each component in the range `[0, 1)`.
+#[cfg(feature = "rand")]
+impl Distribution<Vec3> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        vec3(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{int3, Degrees, Mat4x4, PI};
+
+    #[test]
+    fn slerp_midpoint() {
+        let mid = Vec3::RIGHT.slerp(Vec3::UP, 0.5);
+        assert!(crate::approx_f32(mid.len(), 1.0));
+        assert!(crate::approx_f32(
+            mid.angle_between(Vec3::RIGHT).0,
+            PI / 4.0
+        ));
+        assert!(crate::approx_f32(mid.angle_between(Vec3::UP).0, PI / 4.0));
+        assert!(Vec3::RIGHT.slerp(Vec3::UP, 1.0).approx_eq(&Vec3::UP));
+    }
+
+    #[test]
+    fn recip_and_elements() {
+        assert_eq!(vec3(3.0, 1.0, 2.0).min_element(), 1.0);
+        assert_eq!(vec3(3.0, 1.0, 2.0).max_element(), 3.0);
+        assert_eq!(vec3(2.0, 4.0, -0.5).recip(), vec3(0.5, 0.25, -2.0));
+    }
+
+    #[test]
+    fn catmull_rom_spline_passes_through_points() {
+        let path = [
+            vec3(0.0, 0.0, 0.0),
+            vec3(1.0, 2.0, 1.0),
+            vec3(3.0, 2.0, -1.0),
+        ];
+        for (i, p) in path.iter().enumerate() {
+            let t = i as f32 / 2.0;
+            assert!(Vec3::catmull_rom_spline(&path, t).approx_eq(p));
+        }
+    }
+
+    #[test]
+    fn orthonormal_pair_is_perpendicular() {
+        let dirs = [
+            vec3(0.0, 0.0, 1.0),
+            vec3(0.0, 0.0, -1.0),
+            vec3(1.0, 2.0, -3.0).norm(),
+        ];
+        for n in &dirs {
+            let (a, b) = n.any_orthonormal_pair();
+            assert!(crate::approx_f32(a.len(), 1.0) && crate::approx_f32(b.len(), 1.0));
+            assert!(crate::approx_f32(a.dot(*n), 0.0));
+            assert!(crate::approx_f32(b.dot(*n), 0.0));
+            assert!(crate::approx_f32(a.dot(b), 0.0));
+        }
+        let n = vec3(0.0, 1.0, 0.0);
+        let t = n.orthonormalize(vec3(2.0, 3.0, 0.0));
+        assert!(t.approx_eq(&vec3(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        assert_eq!(vec3(0.7, 1.2, -0.3).snap(0.5), vec3(0.5, 1.0, -0.5));
+        assert_eq!(
+            vec3(0.7, 1.2, -0.3).snap(vec3(0.0, 1.0, 0.0)),
+            vec3(0.7, 1.0, -0.3)
+        );
+    }
+
+    #[test]
+    fn to_int3_rounding_modes() {
+        let v = vec3(-0.5, 1.9, -2.2);
+        assert_eq!(v.floor_to_int3(), int3(-1, 1, -3));
+        assert_eq!(v.ceil_to_int3(), int3(0, 2, -2));
+        assert_eq!(v.round_to_int3(), int3(-1, 2, -2));
+    }
+
+    #[test]
+    fn length_metrics() {
+        let v = vec3(2.0, -3.0, 6.0);
+        assert_eq!(v.len(), 7.0);
+        assert_eq!(v.manhattan_len(), 11.0);
+        assert_eq!(v.chebyshev_len(), 6.0);
+        assert_eq!(Vec3::ONE.manhattan_dist(Vec3::ONE + v), 11.0);
+        assert_eq!(Vec3::ONE.chebyshev_dist(Vec3::ONE + v), 6.0);
+    }
+
+    #[test]
+    fn nan_and_finite_checks() {
+        let v = vec3(1.0, f32::NAN, 0.0);
+        assert!(v.is_nan());
+        assert!(!v.is_finite());
+        assert!(vec3(1.0, 2.0, 3.0).is_finite());
+        let inf = vec3(f32::INFINITY, 0.0, 0.0);
+        assert!(!inf.is_finite() && !inf.is_nan());
+    }
+
+    #[test]
+    fn spherical_round_trip() {
+        for &(r, az, inc) in &[
+            (1.0, 0.0, 0.0),
+            (2.5, 1.0, 0.5),
+            (4.0, -2.5, -1.2),
+            (0.5, 3.0, 1.5),
+        ] {
+            let (r2, az2, inc2) = Vec3::from_spherical(r, az, inc).to_spherical();
+            assert!((r2 - r).abs() < 1e-5);
+            assert!((az2.0 - az).abs() < 1e-5);
+            assert!((inc2.0 - inc).abs() < 1e-5);
+        }
+        let p = Vec3::from_spherical(2.0, Degrees(90.0), Degrees(0.0));
+        assert!(p.approx_eq(&(Vec3::FORWARD * 2.0)));
+        assert!(Vec3::from_spherical(1.0, 0.0, PI / 2.0).approx_eq(&Vec3::UP));
+    }
+
+    #[test]
+    fn within_cone_ahead_and_behind() {
+        let facing = Vec3::FORWARD;
+        assert!(vec3(0.0, 0.0, 10.0).within_cone(facing, Degrees(45.0)));
+        assert!(!vec3(0.0, 0.0, -10.0).within_cone(facing, Degrees(45.0)));
+        assert!(!vec3(0.0, 10.0, 1.0).within_cone(facing, Degrees(45.0)));
+        assert!(!vec3(0.0, 0.0, 1.0).within_cone(Vec3::ZERO, Degrees(45.0)));
+        assert!(crate::approx_f32(Vec3::UP.cos_angle(facing), 0.0));
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        assert_eq!(vec3(1.0, 4.0, 9.0).map(f32::sqrt), vec3(1.0, 2.0, 3.0));
+        let max = vec3(1.0, 5.0, -2.0).zip_with(vec3(3.0, 2.0, -1.0), |a, b| match a > b {
+            true => a,
+            false => b,
+        });
+        assert_eq!(max, vec3(3.0, 5.0, -1.0));
+    }
+
+    #[test]
+    fn refract_identity_and_total_internal_reflection() {
+        let n = Vec3::UP;
+        let i = vec3(1.0, -2.0, 0.5).norm();
+        assert!(i.refract(n, 1.0).unwrap().approx_eq(&i));
+        assert_eq!(vec3(1.0, -0.1, 0.0).norm().refract(n, 1.5), None);
+    }
+
+    #[test]
+    fn angle_between_and_signed_around() {
+        let (a, b) = (Vec3::RIGHT * 2.0, Vec3::FORWARD * 3.0);
+        assert!(crate::approx_f32(a.angle_between(b).0, PI / 2.0));
+        let up = a.signed_angle_around(b, Vec3::UP).0;
+        let down = a.signed_angle_around(b, Vec3::DOWN).0;
+        assert!(crate::approx_f32(up.abs(), PI / 2.0));
+        assert!(crate::approx_f32(down, -up));
+        assert!(crate::approx_f32(a.angle_between(-a).0, PI));
+    }
+
+    #[test]
+    fn project_and_reject() {
+        let v = vec3(1.0, 2.0, 3.0);
+        assert_eq!(v.project(Vec3::UP * 5.0), vec3(0.0, 2.0, 0.0));
+        assert_eq!(v.reject(Vec3::UP * 5.0), vec3(1.0, 0.0, 3.0));
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let v = vec3(1.5, -2.0, 3.25);
+        assert_eq!(v.to_array(), [1.5, -2.0, 3.25]);
+        assert_eq!(Vec3::from_array(v.to_array()), v);
+    }
+
+    #[test]
+    fn max_abs_diff_picks_largest_component() {
+        let a = vec3(1.0, -2.0, 3.0);
+        let b = vec3(1.5, 1.0, 2.0);
+        assert_eq!(a.abs_diff(b), vec3(0.5, 3.0, 1.0));
+        assert_eq!(a.max_abs_diff(b), 3.0);
+        assert_eq!(b.max_abs_diff(a), 3.0);
+        assert_eq!(a.max_abs_diff(a), 0.0);
+    }
+
+    #[test]
+    fn project_on_plane_slides_along_slope() {
+        let normal = vec3(1.0, 1.0, 0.0).norm();
+        let vel = vec3(0.0, -2.0, 0.0);
+        let slide = vel.project_on_plane(normal);
+        assert!(slide.dot(normal).abs() < 1e-6);
+        assert!(slide.max_abs_diff(vec3(1.0, -1.0, 0.0)) < 1e-6);
+
+        let along = vec3(3.0, -3.0, 1.0);
+        assert!(along.project_on_plane(normal).max_abs_diff(along) < 1e-6);
+    }
+
+    #[test]
+    fn slice_read_and_write() {
+        let buf = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(Vec3::from_slice(&buf[2..]), vec3(2.0, 3.0, 4.0));
+        assert_eq!(Vec3::try_from_slice(&buf[4..]), Some(vec3(4.0, 5.0, 6.0)));
+        assert_eq!(Vec3::try_from_slice(&buf[5..]), None);
+        assert_eq!(Vec3::try_from_slice(&[]), None);
+
+        let mut out = [0.0; 7];
+        vec3(7.0, 8.0, 9.0).write_to_slice(&mut out[3..]);
+        assert_eq!(out, [0.0, 0.0, 0.0, 7.0, 8.0, 9.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_slice_too_short_panics() {
+        Vec3::from_slice(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn norm_and_len_matches_separate_calls() {
+        let v = vec3(3.0, -4.0, 12.0);
+        let (n, len) = v.norm_and_len();
+        assert_eq!(len, v.len());
+        assert_eq!(n, v.norm());
+        assert_eq!(Vec3::ZERO.norm_and_len(), (Vec3::ZERO, 0.0));
+    }
+
+    #[test]
+    fn triple_product_and_coplanarity() {
+        let (x, y, z) = (Vec3::RIGHT, Vec3::UP, Vec3::FORWARD);
+        assert_eq!(x.triple_product(y, z), 1.0);
+        assert_eq!(x.triple_product(z, y), -1.0);
+        assert_eq!(y.triple_product(z, x), 1.0);
+        assert_eq!(vec3(2.0, 0.0, 0.0).triple_product(y * 3.0, z * 4.0), 24.0);
+
+        let a = vec3(0.0, 0.0, 0.0);
+        let b = vec3(1.0, 2.0, 0.0);
+        let c = vec3(-3.0, 5.0, 0.0);
+        let d = vec3(4.0, -1.0, 0.0);
+        assert!(are_coplanar(a, b, c, d, 1e-6));
+        assert!(!are_coplanar(a, b, c, d + z * 0.1, 1e-6));
+    }
+
+    #[test]
+    fn rotate_around_axis_matches_matrix() {
+        let v = rotate_around_axis(Vec3::RIGHT, Vec3::UP, Degrees(90.0));
+        assert!(v.max_abs_diff(Vec3::BACK) < 1e-6);
+
+        let axis = vec3(1.0, -2.0, 0.5).norm();
+        let p = vec3(0.3, 4.0, -2.0);
+        let rotated = rotate_around_axis(p, axis, Radians(1.1));
+        let by_matrix = Mat4x4::rotation_axis(axis, 1.1).transform3(&p);
+        assert!(rotated.max_abs_diff(by_matrix) < 1e-5);
+        assert!((rotated.len() - p.len()).abs() < 1e-5);
+        assert!(rotate_around_axis(axis * 3.0, axis, Radians(2.0)).max_abs_diff(axis * 3.0) < 1e-5);
+    }
+
+    #[test]
+    fn extents_and_centroid_of_points() {
+        let pts = [
+            vec3(1.0, -2.0, 3.0),
+            vec3(-4.0, 5.0, 0.0),
+            vec3(2.0, 0.0, -6.0),
+            vec3(5.0, 1.0, 7.0),
+        ];
+        assert_eq!(Vec3::min_components(&pts), vec3(-4.0, -2.0, -6.0));
+        assert_eq!(Vec3::max_components(&pts), vec3(5.0, 5.0, 7.0));
+        assert_eq!(Vec3::centroid(&pts), vec3(1.0, 1.0, 1.0));
+        assert_eq!(Vec3::min_components(&pts[..1]), pts[0]);
+        assert_eq!(Vec3::min_components(&[]), Vec3::ZERO);
+        assert_eq!(Vec3::max_components(&[]), Vec3::ZERO);
+        assert_eq!(Vec3::centroid(&[]), Vec3::ZERO);
+    }
+
+    #[test]
+    fn slerp_nearly_opposite_stays_in_plane() {
+        let (s, c) = 3.12f32.sin_cos();
+        let b = vec3(c, s, 0.0);
+        let end = Vec3::RIGHT.slerp(b, 1.0);
+        assert!(end.max_abs_diff(b) < 1e-5);
+        for t in [0.25, 0.5, 0.75] {
+            let p = Vec3::RIGHT.slerp(b, t);
+            assert!(p.z.abs() < 1e-6);
+            assert!((p.len() - 1.0).abs() < 1e-5);
+        }
+
+        let flip = Vec3::RIGHT.slerp(Vec3::LEFT, 0.5);
+        assert!((flip.len() - 1.0).abs() < 1e-5);
+        assert!(flip.dot(Vec3::RIGHT).abs() < 1e-5);
+    }
+}
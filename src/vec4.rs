@@ -1,13 +1,25 @@
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Neg, Sub, AddAssign, SubAssign, MulAssign, DivAssign, RemAssign, Rem, Index};
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::float::Float;
 use crate::Vec3;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 /// A four-dimensional floating point vector.
 #[derive(Default, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 #[repr(C)]
 pub struct Vec4 {
     pub x: f32,
@@ -17,7 +29,7 @@ pub struct Vec4 {
 }
 
 /// Easy constructor.
-pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
+pub const fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
     Vec4 { x, y, z, w }
 }
 
@@ -39,15 +51,77 @@ impl Vec4 {
     };
 
     /// Create a new vector.
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         vec4(x, y, z, w)
     }
 
+    /// The vector's components as an array.
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Create a new vector from an array of components.
+    pub fn from_array(a: [f32; 4]) -> Self {
+        vec4(a[0], a[1], a[2], a[3])
+    }
+
+    /// Read the vector from the first 4 floats of `s`. Panics if `s` is too short.
+    pub fn from_slice(s: &[f32]) -> Self {
+        vec4(s[0], s[1], s[2], s[3])
+    }
+
+    /// Read the vector from the first 4 floats of `s`, or `None` if `s` is too short.
+    pub fn try_from_slice(s: &[f32]) -> Option<Self> {
+        match s.len() >= 4 {
+            true => Some(Self::from_slice(s)),
+            false => None,
+        }
+    }
+
+    /// Write the vector into the first 4 floats of `s`. Panics if `s` is too short.
+    pub fn write_to_slice(&self, s: &mut [f32]) {
+        s[..4].copy_from_slice(&self.to_array());
+    }
+
     /// Compose a new vector from a `Vec3` and the provided `w` axis.
     pub fn from(val: Vec3, w: f32) -> Self {
         vec4(val.x, val.y, val.z, w)
     }
 
+    /// Drop the w-axis of the vector.
+    pub fn truncate(&self) -> Vec3 {
+        crate::vec3(self.x, self.y, self.z)
+    }
+
+    /// Drop the w-axis of the vector, same as `truncate`.
+    pub fn xyz(&self) -> Vec3 {
+        self.truncate()
+    }
+
+    /// The x and y axes of the vector.
+    pub fn xy(&self) -> crate::Vec2 {
+        crate::vec2(self.x, self.y)
+    }
+
+    /// The x and z axes of the vector.
+    pub fn xz(&self) -> crate::Vec2 {
+        crate::vec2(self.x, self.z)
+    }
+
+    /// The y and z axes of the vector.
+    pub fn yz(&self) -> crate::Vec2 {
+        crate::vec2(self.y, self.z)
+    }
+
+    /// Divide the x, y and z axes by the w-axis, such as to convert a projected point into
+    /// normalized device coordinates. If w is zero, the axes are returned undivided.
+    pub fn perspective_divide(&self) -> Vec3 {
+        match self.w == 0.0 {
+            true => self.truncate(),
+            false => self.truncate() / self.w,
+        }
+    }
+
     /// The length of the vector, squared.
     pub fn sqr_len(&self) -> f32 {
         self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
@@ -58,12 +132,50 @@ impl Vec4 {
         self.sqr_len().sqrt()
     }
 
+    /// Check if all of the vector's components are finite, neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Check if any of the vector's components are NaN.
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Check if two vectors are approximately equal, component by component.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        crate::approx(self, other)
+    }
+
+    /// The absolute difference between each of the vectors' components.
+    pub fn abs_diff(&self, other: Self) -> Self {
+        (*self - other).abs()
+    }
+
+    /// The largest absolute difference between any of the vectors' components.
+    pub fn max_abs_diff(&self, other: Self) -> f32 {
+        self.abs_diff(other).max_element()
+    }
+
     /// Normalize the vector.
     pub fn norm(&self) -> Self {
         let len = self.len();
         vec4(self.x / len, self.y / len, self.z / len, self.w / len)
     }
 
+    /// Normalize the vector, also returning its length from before. The zero vector returns
+    /// `(ZERO, 0.0)`.
+    pub fn norm_and_len(&self) -> (Self, f32) {
+        let len = self.len();
+        match len > 0.0 {
+            true => (
+                vec4(self.x / len, self.y / len, self.z / len, self.w / len),
+                len,
+            ),
+            false => (Self::ZERO, 0.0),
+        }
+    }
+
     /// Zero the vector's y, z, and w axes.
     pub fn only_x(&self) -> Self {
         vec4(self.x, 0.0, 0.0, 0.0)
@@ -84,6 +196,21 @@ impl Vec4 {
         vec4(0.0, 0.0, 0.0, self.w)
     }
 
+    /// Apply a function to each of the vector's components.
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        vec4(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
+    /// Combine the components of two vectors pairwise with a function.
+    pub fn zip_with(&self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        vec4(
+            f(self.x, other.x),
+            f(self.y, other.y),
+            f(self.z, other.z),
+            f(self.w, other.w),
+        )
+    }
+
     /// Absolute the vector's components.
     pub fn abs(&self) -> Self {
         vec4(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
@@ -129,6 +256,21 @@ impl Vec4 {
         )
     }
 
+    /// Return the smallest of the vector's components.
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    /// Return the largest of the vector's components.
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    /// Return the reciprocal of the vector's components.
+    pub fn recip(&self) -> Self {
+        vec4(1.0 / self.x, 1.0 / self.y, 1.0 / self.z, 1.0 / self.w)
+    }
+
     /// Return the sign of the vector's components.
     pub fn sign(&self) -> Self {
         vec4(
@@ -158,6 +300,25 @@ impl Vec4 {
         self.sqr_dist(other).sqrt()
     }
 
+    /// Return the dot product of two vectors.
+    pub fn dot(&self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Project the vector onto `onto`, returning the part of it that points along `onto`.
+    pub fn project(&self, onto: Self) -> Self {
+        let sqr_len = onto.sqr_len();
+        match sqr_len > 0.0 {
+            true => onto * (self.dot(onto) / sqr_len),
+            false => Self::ZERO,
+        }
+    }
+
+    /// Reject the vector from `onto`, returning the part of it perpendicular to `onto`.
+    pub fn reject(&self, onto: Self) -> Self {
+        *self - self.project(onto)
+    }
+
     /// Linear interpolation between two vectors by a factor `t`.
     /// For example, `t = 0.5` would return the midpoint between the two vectors.
     pub fn lerp(&self, other: Self, t: f32) -> Self {
@@ -169,6 +330,12 @@ impl Vec4 {
         )
     }
 
+    /// Linear interpolation between two vectors by a factor `t`, clamped in range (0.0 - 1.0)
+    /// so the result never extrapolates past either vector.
+    pub fn lerp_clamped(&self, other: Self, t: f32) -> Self {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+
     /// Quadratic bezier interpolation by a factor `t`, using `b` as the anchor point.
     pub fn bezier3(&self, b: Self, c: Self, t: f32) -> Self {
         vec4(
@@ -213,11 +380,16 @@ impl Vec4 {
     pub fn smooth_step(&self, target: Self, t: f32) -> Self {
         self.lerp(target, crate::smooth_step(t))
     }
+
+    /// Smoother-step interpolation between vectors by factor `t`.
+    pub fn smoother_step(&self, target: Self, t: f32) -> Self {
+        self.lerp(target, crate::smoother_step(t))
+    }
 }
 
 impl AsRef<[f32]> for Vec4 {
     fn as_ref(&self) -> &[f32] {
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const f32, 4) }
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const f32, 4) }
     }
 }
 
@@ -261,6 +433,18 @@ impl From<crate::Vec3> for Vec4 {
     }
 }
 
+impl From<crate::Int2> for Vec4 {
+    fn from(val: crate::Int2) -> Self {
+        vec4(val.x as f32, val.y as f32, 0.0, 0.0)
+    }
+}
+
+impl From<crate::Int3> for Vec4 {
+    fn from(val: crate::Int3) -> Self {
+        vec4(val.x as f32, val.y as f32, val.z as f32, 0.0)
+    }
+}
+
 impl From<Vec4> for crate::Vec2 {
     fn from(val: Vec4) -> Self {
         crate::vec2(val.x, val.y)
@@ -439,4 +623,125 @@ impl RemAssign<f32> for Vec4 {
     fn rem_assign(&mut self, rhs: f32) {
         *self = self.rem(rhs);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector4<f32>> for Vec4 {
+    fn from(val: mint::Vector4<f32>) -> Self {
+        vec4(val.x, val.y, val.z, val.w)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vec4> for mint::Vector4<f32> {
+    fn from(val: Vec4) -> Self {
+        mint::Vector4 {
+            x: val.x,
+            y: val.y,
+            z: val.z,
+            w: val.w,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec4> for Vec4 {
+    fn from(val: glam::Vec4) -> Self {
+        vec4(val.x, val.y, val.z, val.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec4> for glam::Vec4 {
+    fn from(val: Vec4) -> Self {
+        glam::Vec4::new(val.x, val.y, val.z, val.w)
+    }
+}
+
+/// Samples each component in the range `[0, 1)`.
+#[cfg(feature = "rand")]
+impl Distribution<Vec4> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4 {
+        vec4(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{int2, int3, vec2, vec3, Mat4x4};
+
+    #[test]
+    fn recip_and_elements() {
+        let v = vec4(3.0, 1.0, 2.0, 4.0);
+        assert_eq!(v.min_element(), 1.0);
+        assert_eq!(v.max_element(), 4.0);
+        assert_eq!(
+            vec4(2.0, 4.0, -0.5, 1.0).recip(),
+            vec4(0.5, 0.25, -2.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn perspective_divide_to_ndc() {
+        let proj = Mat4x4::perspective_fov_rh_zo(1.2, 1.0, 0.1, 100.0);
+        let clip = proj.transform4(&vec4(0.5, -0.5, -10.0, 1.0));
+        let ndc = clip.perspective_divide();
+        assert!(ndc.x > 0.0 && ndc.x < 1.0);
+        assert!(ndc.y < 0.0 && ndc.y > -1.0);
+        assert!(ndc.z > 0.0 && ndc.z < 1.0);
+        let v = vec4(1.0, 2.0, 3.0, 0.0);
+        assert_eq!(v.perspective_divide(), v.xyz());
+        assert_eq!(vec4(2.0, 4.0, 6.0, 2.0).perspective_divide(), v.truncate());
+    }
+
+    #[test]
+    fn swizzles_and_int_conversions() {
+        let v = vec4(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.xyz(), vec3(1.0, 2.0, 3.0));
+        assert_eq!(v.xy(), vec2(1.0, 2.0));
+        assert_eq!(v.xz(), vec2(1.0, 3.0));
+        assert_eq!(v.yz(), vec2(2.0, 3.0));
+        assert_eq!(v.xyz().xz(), vec2(1.0, 3.0));
+        let v: Vec4 = int3(1, 2, 3).into();
+        assert_eq!(v, vec4(1.0, 2.0, 3.0, 0.0));
+        let v: Vec4 = int2(-1, 2).into();
+        assert_eq!(v, vec4(-1.0, 2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn nan_and_finite_checks() {
+        let v = vec4(0.0, 0.0, 0.0, f32::NAN);
+        assert!(v.is_nan() && !v.is_finite());
+        assert!(Vec4::ZERO.is_finite());
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        assert_eq!(
+            vec4(1.0, 4.0, 9.0, 16.0).map(f32::sqrt),
+            vec4(1.0, 2.0, 3.0, 4.0)
+        );
+        let v = vec4(1.0, 5.0, -2.0, 0.0).zip_with(vec4(3.0, 2.0, -1.0, 0.0), f32::max);
+        assert_eq!(v, vec4(3.0, 5.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn project_onto_basis() {
+        let v = vec4(1.0, 2.0, 3.0, 4.0);
+        let axis = vec4(0.0, 0.0, 2.0, 0.0);
+        assert_eq!(v.project(axis), vec4(0.0, 0.0, 3.0, 0.0));
+        assert_eq!(v.reject(axis), vec4(1.0, 2.0, 0.0, 4.0));
+        assert_eq!(v.project(axis) + v.reject(axis), v);
+        assert_eq!(v.dot(vec4(0.0, 0.0, 0.0, 1.0)), 4.0);
+    }
+
+    #[test]
+    fn norm_and_len_matches_separate_calls() {
+        let v = vec4(1.0, -2.0, 2.0, 4.0);
+        let (n, len) = v.norm_and_len();
+        assert_eq!(len, v.len());
+        assert_eq!(n, v.norm());
+        assert_eq!(Vec4::ZERO.norm_and_len(), (Vec4::ZERO, 0.0));
+    }
+}